@@ -0,0 +1,31 @@
+use async_signal::{Signal, Signals};
+use criterion::{criterion_group, criterion_main, Criterion};
+use futures_lite::{future, prelude::*};
+
+fn create(c: &mut Criterion) {
+    c.bench_function("create", |b| {
+        b.iter(|| Signals::new(Some(Signal::Int)).unwrap());
+    });
+}
+
+fn poll_empty(c: &mut Criterion) {
+    let mut signals = Signals::new(Some(Signal::Int)).unwrap();
+
+    c.bench_function("poll_empty", |b| {
+        b.iter(|| future::block_on(future::poll_once(signals.next())));
+    });
+}
+
+fn poll_with_signal(c: &mut Criterion) {
+    let mut signals = Signals::new(Some(Signal::Int)).unwrap();
+
+    c.bench_function("poll_with_signal", |b| {
+        b.iter(|| {
+            signals.inject(Signal::Int);
+            future::block_on(signals.next())
+        });
+    });
+}
+
+criterion_group!(benches, create, poll_empty, poll_with_signal);
+criterion_main!(benches);
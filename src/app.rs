@@ -0,0 +1,156 @@
+//! Giving signals application-specific names.
+
+use crate::{Signal, SignalSet, Signals};
+
+use futures_core::ready;
+use futures_core::stream::{FusedStream, Stream};
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A signal with an application-specific name.
+///
+/// Signals like `SIGUSR1` and `SIGUSR2` are commonly repurposed as triggers with a meaning that
+/// is specific to the application, e.g. "reload" or "dump state". An `AppSignal` attaches that
+/// meaning to the signal, and [`AppSignals`] reports the received signals by their names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AppSignal {
+    /// The name of the signal in the application.
+    name: &'static str,
+
+    /// The signal that is used.
+    signal: Signal,
+}
+
+impl AppSignal {
+    /// Create a new application signal.
+    pub const fn new(name: &'static str, signal: Signal) -> Self {
+        Self { name, signal }
+    }
+
+    /// Create a new application signal that uses `SIGUSR1`.
+    pub const fn usr1(name: &'static str) -> Self {
+        Self::new(name, Signal::Usr1)
+    }
+
+    /// Create a new application signal that uses `SIGUSR2`.
+    pub const fn usr2(name: &'static str) -> Self {
+        Self::new(name, Signal::Usr2)
+    }
+
+    /// Get the name of the signal in the application.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the signal that is used.
+    pub fn signal(&self) -> Signal {
+        self.signal
+    }
+}
+
+/// A stream of [`AppSignal`]s, reported by their names.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_signal::{AppSignal, AppSignals};
+/// use futures_lite::prelude::*;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut signals = AppSignals::new([AppSignal::usr1("reload"), AppSignal::usr2("dump")])?;
+///
+/// while let Some(name) = signals.next().await {
+///     match name? {
+///         "reload" => println!("Reloading..."),
+///         "dump" => println!("Dumping state..."),
+///         _ => unreachable!(),
+///     }
+/// }
+/// # std::io::Result::Ok(())
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AppSignals {
+    /// The underlying signals.
+    signals: Signals,
+
+    /// The application signals that are registered.
+    app_signals: Vec<AppSignal>,
+}
+
+impl AppSignals {
+    /// Register a set of application signals.
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if two of them use
+    /// the same signal, since a received signal could then not be told apart.
+    pub fn new(app_signals: impl IntoIterator<Item = AppSignal>) -> io::Result<Self> {
+        let app_signals: Vec<_> = app_signals.into_iter().collect();
+
+        let mut set = SignalSet::new();
+        for app_signal in &app_signals {
+            if !set.insert(app_signal.signal) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "{} is used by more than one app signal",
+                        app_signal.signal.name()
+                    ),
+                ));
+            }
+        }
+
+        Ok(Self {
+            signals: Signals::new(set)?,
+            app_signals,
+        })
+    }
+
+    /// Get the application signals that are registered.
+    pub fn app_signals(&self) -> &[AppSignal] {
+        &self.app_signals
+    }
+
+    /// Get the underlying [`Signals`].
+    pub fn signals(&self) -> &Signals {
+        &self.signals
+    }
+
+    /// Get the name of a signal.
+    fn name_of(&self, signal: Signal) -> Option<&'static str> {
+        self.app_signals
+            .iter()
+            .find(|app_signal| app_signal.signal == signal)
+            .map(AppSignal::name)
+    }
+}
+
+impl Stream for AppSignals {
+    type Item = io::Result<&'static str>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let signal = ready!(self.signals.poll_signal(cx))?;
+
+            // Only the registered signals are received, but be defensive about it.
+            if let Some(name) = self.name_of(signal) {
+                return Poll::Ready(Some(Ok(name)));
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // This stream is expected to never end.
+        (usize::MAX, None)
+    }
+}
+
+impl FusedStream for AppSignals {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        // This stream is expected to never end.
+        false
+    }
+}
@@ -0,0 +1,147 @@
+//! A builder for configuring [`Signals`].
+
+use crate::{Signal, SignalSet, Signals};
+
+use std::borrow::Borrow;
+use std::io;
+
+/// A builder for configuring [`Signals`].
+///
+/// Every option corresponds to a setter on [`Signals`], which can also be used to change it after
+/// the fact.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_signal::{Signal, Signals};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let signals = Signals::builder()
+///     .signals([Signal::Term, Signal::Int])
+///     .capacity(4)
+///     .coalesce(true)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SignalsBuilder {
+    /// The signals to register.
+    signals: SignalSet,
+
+    /// The maximum number of signals waiting in the pipe.
+    capacity: Option<usize>,
+
+    /// Whether signals are coalesced.
+    coalesce: bool,
+
+    /// Whether signals are kept when the pipe is full.
+    unbounded: bool,
+
+    /// Whether signals are counted.
+    counting: bool,
+
+    /// The priority of signals.
+    priority: Vec<Signal>,
+
+    /// Whether signal types take turns.
+    round_robin: bool,
+
+    /// The maximum number of signals that are taken at once.
+    batch_size: Option<usize>,
+}
+
+impl SignalsBuilder {
+    /// Create a new builder with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add signals to the set of signals to wait for.
+    ///
+    /// See [`Signals::add_signals`].
+    pub fn signals<B>(mut self, signals: impl IntoIterator<Item = B>) -> Self
+    where
+        B: Borrow<Signal>,
+    {
+        self.signals
+            .extend(signals.into_iter().map(|signal| *signal.borrow()));
+        self
+    }
+
+    /// Set the maximum number of signals that can be waiting in the internal pipe.
+    ///
+    /// See [`Signals::set_capacity`].
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether signals that are already waiting to be received are merged with new ones.
+    ///
+    /// See [`Signals::set_coalescing`].
+    pub fn coalesce(mut self, coalesce: bool) -> Self {
+        self.coalesce = coalesce;
+        self
+    }
+
+    /// Set whether signals should be kept when the internal pipe is full.
+    ///
+    /// See [`Signals::set_unbounded`].
+    pub fn unbounded(mut self, unbounded: bool) -> Self {
+        self.unbounded = unbounded;
+        self
+    }
+
+    /// Set whether to count how many times each signal is received.
+    ///
+    /// See [`Signals::set_counting`].
+    pub fn counting(mut self, counting: bool) -> Self {
+        self.counting = counting;
+        self
+    }
+
+    /// Set the order in which signals that are received at the same time are returned.
+    ///
+    /// See [`Signals::set_priority`].
+    pub fn priority<B>(mut self, order: impl IntoIterator<Item = B>) -> Self
+    where
+        B: Borrow<Signal>,
+    {
+        self.priority = order.into_iter().map(|signal| *signal.borrow()).collect();
+        self
+    }
+
+    /// Set whether queued signals are returned in turns, one signal type at a time.
+    ///
+    /// See [`Signals::set_round_robin`].
+    pub fn round_robin(mut self, round_robin: bool) -> Self {
+        self.round_robin = round_robin;
+        self
+    }
+
+    /// Set the maximum number of signals that are taken at once.
+    ///
+    /// See [`Signals::set_batch_size`].
+    pub fn batch_size(mut self, size: usize) -> Self {
+        self.batch_size = Some(size);
+        self
+    }
+
+    /// Create the [`Signals`] instance.
+    pub fn build(self) -> io::Result<Signals> {
+        let mut signals = Signals::new_empty()?;
+
+        // Configure the notifier before any signal can arrive.
+        signals.set_capacity(self.capacity);
+        signals.set_coalescing(self.coalesce);
+        signals.set_unbounded(self.unbounded);
+        signals.set_counting(self.counting);
+        signals.set_priority(self.priority);
+        signals.set_round_robin(self.round_robin);
+        signals.set_batch_size(self.batch_size);
+
+        signals.add_signals(self.signals)?;
+        Ok(signals)
+    }
+}
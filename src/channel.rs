@@ -6,11 +6,17 @@
 use crate::Signal;
 
 use atomic_waker::AtomicWaker;
+use windows_sys::Win32::Foundation::HANDLE;
+use windows_sys::Win32::System::Threading::{CreateEventW, ResetEvent, SetEvent};
 
 use std::io;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::os::windows::io::{
+    AsHandle, AsRawHandle, BorrowedHandle, FromRawHandle, OwnedHandle, RawHandle,
+};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::task::{Context, Poll};
+use std::task::{Context, Poll, Waker};
 
 /// The notifier that uses an asynchronous channel.
 #[derive(Debug)]
@@ -22,10 +28,28 @@ pub struct Notifier {
 impl Notifier {
     /// Create a new signal notifier.
     pub(super) fn new() -> io::Result<Self> {
+        // Create a manual-reset event that is set while signals are available.
+        let event = unsafe { CreateEventW(ptr::null(), true as _, false as _, ptr::null()) };
+        if event.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        // SAFETY: We just created this handle, so we own it.
+        let event = unsafe { OwnedHandle::from_raw_handle(event as RawHandle) };
+
         Ok(Self {
             pipe: Arc::new(Pipe {
+                event,
                 count: AtomicUsize::new(0),
+                counts: Default::default(),
                 waker: AtomicWaker::new(),
+                unbounded: AtomicBool::new(false),
+                coalesce: AtomicBool::new(false),
+                capacity: AtomicUsize::new(usize::MAX),
+                dropped: Default::default(),
+                any_dropped: AtomicBool::new(false),
+                drop_oldest: AtomicBool::new(false),
+                merged: Default::default(),
             }),
         })
     }
@@ -35,11 +59,31 @@ impl Notifier {
     /// Returns a closure to be passed to signal-hook.
     pub(super) fn add_signal(
         &mut self,
-        _signal: Signal,
+        signal: Signal,
     ) -> io::Result<impl Fn() + Send + Sync + 'static> {
+        let slot = slot(signal)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "unsupported signal"))?;
         let pipe = self.pipe.clone();
         Ok(move || {
-            pipe.push();
+            pipe.push(slot);
+        })
+    }
+
+    /// Deliver a signal as if it had been received.
+    pub(super) fn inject(&self, signal: Signal) {
+        if let Some(slot) = slot(signal) {
+            self.pipe.push(slot);
+        }
+    }
+
+    /// Get a closure that delivers signals as if they had been received.
+    pub(super) fn injector(&self) -> io::Result<impl Fn(Signal) + Send + Sync + 'static> {
+        let pipe = self.pipe.clone();
+
+        Ok(move |signal: Signal| {
+            if let Some(slot) = slot(signal) {
+                pipe.push(slot);
+            }
         })
     }
 
@@ -48,58 +92,269 @@ impl Notifier {
         Ok(())
     }
 
+    /// Set whether signals that do not fit into the queue should be kept.
+    pub(super) fn set_unbounded(&mut self, unbounded: bool) {
+        self.pipe.unbounded.store(unbounded, Ordering::SeqCst);
+    }
+
+    /// Set whether a signal that is already pending should be merged with new occurrences.
+    pub(super) fn set_coalesce(&mut self, coalesce: bool) {
+        self.pipe.coalesce.store(coalesce, Ordering::SeqCst);
+    }
+
+    /// Set whether the oldest signal in the queue is dropped to make room for a new one.
+    pub(super) fn set_drop_oldest(&mut self, drop_oldest: bool) {
+        self.pipe.drop_oldest.store(drop_oldest, Ordering::SeqCst);
+    }
+
+    /// Set the maximum number of signals that can be queued at once.
+    pub(super) fn set_capacity(&mut self, capacity: Option<usize>) {
+        self.pipe
+            .capacity
+            .store(capacity.unwrap_or(usize::MAX), Ordering::SeqCst);
+    }
+
+    /// Take the number of occurrences of a signal that were merged into a pending one.
+    pub(super) fn take_merged(&self, signal: Signal) -> usize {
+        match slot(signal) {
+            Some(slot) => self.pipe.merged[slot].swap(0, Ordering::SeqCst),
+            None => 0,
+        }
+    }
+
+    /// Get the maximum number of signals that can be queued at once.
+    pub(super) fn capacity(&self) -> Option<usize> {
+        match self.pipe.capacity.load(Ordering::SeqCst) {
+            usize::MAX => None,
+            capacity => Some(capacity),
+        }
+    }
+
+    /// Take the number of signals that were dropped since the last call, for each signal.
+    pub(super) fn take_dropped(&self) -> impl Iterator<Item = (Signal, usize)> + '_ {
+        // Clear the flag before taking the counts, so that a drop in between isn't missed.
+        let any = self.pipe.any_dropped.load(Ordering::SeqCst)
+            && self.pipe.any_dropped.swap(false, Ordering::SeqCst);
+
+        SIGNALS
+            .iter()
+            .zip(self.pipe.dropped.iter())
+            .take(if any { SIGNALS.len() } else { 0 })
+            .filter_map(
+                |(signal, dropped)| match dropped.swap(0, Ordering::SeqCst) {
+                    0 => None,
+                    count => Some((*signal, count)),
+                },
+            )
+    }
+
+    /// Register a waker to be woken once a signal may be available.
+    pub(super) fn register(&self, waker: &Waker) {
+        self.pipe.waker.register(waker);
+
+        if self.pipe.count.load(Ordering::SeqCst) > 0 {
+            waker.wake_by_ref();
+        }
+    }
+
+    /// Wait until a signal may be available, without taking it.
+    pub(super) fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if self.pipe.count.load(Ordering::SeqCst) > 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        // Register the waker and check again, in case a signal came in in the meantime.
+        self.pipe.waker.register(cx.waker());
+
+        if self.pipe.count.load(Ordering::SeqCst) > 0 {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
     /// Get the next signal.
     pub(super) fn poll_next(&self, cx: &mut Context<'_>) -> Poll<io::Result<Signal>> {
-        let mut count = self.pipe.count.load(Ordering::SeqCst);
-        let mut registered = false;
-
-        loop {
-            // If the count is greater than zero, then we have a signal.
-            if count > 0 {
-                // Decrement the count.
-                let new_count = count - 1;
-                if let Err(new_count) = self.pipe.count.compare_exchange(
-                    count,
-                    new_count,
-                    Ordering::SeqCst,
-                    Ordering::SeqCst,
-                ) {
-                    count = new_count;
-                    continue;
-                }
+        // If we already have a signal, return it.
+        if let Some(signal) = self.try_next()? {
+            return Poll::Ready(Ok(signal));
+        }
 
-                // Return the signal.
-                return Poll::Ready(Ok(Signal::Int));
-            }
+        // Register the waker and try again, in case a signal came in in the meantime.
+        self.pipe.waker.register(cx.waker());
 
-            // If the count is zero, then we need to wait for a signal.
-            if registered {
-                // We are already registered, so we need to wait for a signal.
-                return Poll::Pending;
-            } else {
-                registered = true;
-                self.pipe.waker.register(cx.waker());
+        match self.try_next()? {
+            Some(signal) => Poll::Ready(Ok(signal)),
+            None => Poll::Pending,
+        }
+    }
 
-                // Try again.
-                count = self.pipe.count.load(Ordering::SeqCst);
-            }
+    /// Get the next signal without waiting.
+    pub(super) fn try_next(&self) -> io::Result<Option<Signal>> {
+        // Take a signal out of its slot first, so that the total never drops below the number
+        // of signals in the slots.
+        let slot = match (0..SIGNALS.len()).find(|&slot| self.pipe.take(slot)) {
+            Some(slot) => slot,
+            None => return Ok(None),
+        };
+
+        if self.pipe.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.pipe.reset_event();
         }
+
+        Ok(Some(SIGNALS[slot]))
+    }
+}
+
+impl AsRawHandle for Notifier {
+    fn as_raw_handle(&self) -> RawHandle {
+        self.pipe.event.as_raw_handle()
     }
 }
 
+impl AsHandle for Notifier {
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        self.pipe.event.as_handle()
+    }
+}
+
+/// The signals that can be registered, in the order of their slots.
+const SIGNALS: [Signal; 2] = [Signal::Int, Signal::Break];
+
+/// Get the slot of a signal, if it can be registered.
+fn slot(signal: Signal) -> Option<usize> {
+    SIGNALS.iter().position(|s| *s == signal)
+}
+
 #[derive(Debug)]
 struct Pipe {
-    /// The number of SIGINT signals received.
+    /// The event that is set while signals are available.
+    event: OwnedHandle,
+
+    /// The total number of signals received.
     count: AtomicUsize,
 
+    /// The number of signals received, for each slot.
+    counts: [AtomicUsize; 2],
+
     /// The waker to wake up.
     waker: AtomicWaker,
+
+    /// Whether signals should be kept even if the queue is full.
+    unbounded: AtomicBool,
+
+    /// Whether a signal that is already pending should be merged with new occurrences.
+    coalesce: AtomicBool,
+
+    /// The maximum number of signals that can be queued at once.
+    capacity: AtomicUsize,
+
+    /// The number of dropped signals that have not been reported yet, for each slot.
+    dropped: [AtomicUsize; 2],
+
+    /// Whether any of the counts in `dropped` may be nonzero.
+    any_dropped: AtomicBool,
+
+    /// Whether the oldest signal in the queue is dropped to make room for a new one.
+    drop_oldest: AtomicBool,
+
+    /// The number of occurrences that were merged into a pending signal and not taken yet, for
+    /// each slot.
+    merged: [AtomicUsize; 2],
 }
 
 impl Pipe {
     /// Add a signal to the notifier.
-    fn push(&self) {
-        self.count.fetch_add(1, Ordering::SeqCst);
-        self.waker.wake();
+    fn push(&self, slot: usize) {
+        // If the signal is already pending, merge this occurrence into it.
+        if self.coalesce.load(Ordering::SeqCst) && self.counts[slot].load(Ordering::SeqCst) > 0 {
+            self.merged[slot].fetch_add(1, Ordering::SeqCst);
+            return;
+        }
+
+        let capacity = if self.unbounded.load(Ordering::SeqCst) {
+            usize::MAX
+        } else {
+            self.capacity.load(Ordering::SeqCst)
+        };
+
+        let res = self
+            .count
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                if count >= capacity {
+                    None
+                } else {
+                    Some(count + 1)
+                }
+            });
+
+        match res {
+            Ok(_) => {
+                self.counts[slot].fetch_add(1, Ordering::SeqCst);
+                unsafe {
+                    SetEvent(self.event_handle());
+                }
+                self.waker.wake();
+            }
+            Err(_) => {
+                // The order of the signals isn't kept, so drop one of the queued signals, with
+                // the other signal first.
+                let oldest = if self.drop_oldest.load(Ordering::SeqCst) {
+                    (0..SIGNALS.len())
+                        .rev()
+                        .map(|offset| (slot + offset) % SIGNALS.len())
+                        .find(|&oldest| self.take(oldest))
+                } else {
+                    None
+                };
+
+                match oldest {
+                    Some(oldest) => {
+                        self.record_dropped(oldest);
+                        self.counts[slot].fetch_add(1, Ordering::SeqCst);
+                        self.waker.wake();
+                    }
+                    None => {
+                        self.record_dropped(slot);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Count a dropped signal.
+    fn record_dropped(&self, slot: usize) {
+        self.dropped[slot].fetch_add(1, Ordering::SeqCst);
+        self.any_dropped.store(true, Ordering::SeqCst);
+    }
+
+    /// Take a signal out of a slot, without changing the total.
+    ///
+    /// Returns `false` if the slot is empty.
+    fn take(&self, slot: usize) -> bool {
+        self.counts[slot]
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                count.checked_sub(1)
+            })
+            .is_ok()
+    }
+
+    /// Reset the event once no signals are left.
+    fn reset_event(&self) {
+        unsafe {
+            ResetEvent(self.event_handle());
+        }
+
+        // A signal may have come in before the event was reset.
+        if self.count.load(Ordering::SeqCst) > 0 {
+            unsafe {
+                SetEvent(self.event_handle());
+            }
+        }
+    }
+
+    /// Get the raw handle of the event.
+    fn event_handle(&self) -> HANDLE {
+        self.event.as_raw_handle() as HANDLE
     }
 }
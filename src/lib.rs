@@ -12,10 +12,10 @@
 //! receive it and notify the user. Asynchronous notification is done through the [`async-io`] crate.
 //!
 //! Note that the internal pipe has a limited capacity. Once it has reached capacity, additional
-//! signals will be dropped.
+//! signals will be dropped, unless [`Signals::set_unbounded`] is used.
 //!
-//! On Windows, a different implementation that only supports `SIGINT` is used. This implementation
-//! uses a channel to notify the user.
+//! On Windows, a different implementation that only supports `SIGINT` and [`Signal::Break`] is
+//! used. This implementation uses a channel to notify the user.
 //!
 //! [`signal_hook_registry`]: https://crates.io/crates/signal-hook-registry
 //! [`async-io`]: https://crates.io/crates/async-io
@@ -75,20 +75,50 @@ cfg_if::cfg_if! {
     }
 }
 
+mod app;
+mod builder;
+pub mod set;
+
+pub use app::{AppSignal, AppSignals};
+pub use builder::SignalsBuilder;
+pub use set::SignalSet;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(target_os = "linux")]
+mod pidfd;
+
+#[cfg(target_os = "linux")]
+pub use pidfd::ChildSignal;
+
+#[cfg(target_os = "linux")]
+pub mod sigwait;
+
+#[cfg(target_os = "linux")]
+pub use sigwait::BlockingSignals;
+
 use futures_core::ready;
-use futures_core::stream::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use registry::SigId;
 
-use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::borrow::{Borrow, Cow};
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
+use std::future::Future;
 use std::io;
 use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Instant;
 
 #[cfg(unix)]
 use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, RawFd};
 
+#[cfg(windows)]
+use std::os::windows::io::{AsHandle, AsRawHandle, BorrowedHandle, RawHandle};
+
 mod signum {
     pub(crate) use std::os::raw::c_int;
 
@@ -159,6 +189,9 @@ macro_rules! define_signal_enum {
         }
 
         impl Signal {
+            /// All of the signals, in order of declaration.
+            const ALL: &'static [Signal] = &[$(Signal::$name,)*];
+
             /// Returns the signal number.
             fn number(self) -> std::os::raw::c_int {
                 match self {
@@ -169,7 +202,6 @@ macro_rules! define_signal_enum {
             }
 
             /// Parse a signal from its number.
-            #[cfg(unix)]
             fn from_number(number: std::os::raw::c_int) -> Option<Self> {
                 match number {
                     $(
@@ -178,6 +210,49 @@ macro_rules! define_signal_enum {
                     _ => None,
                 }
             }
+
+            /// Returns the conventional name of the signal, e.g. `"SIGINT"`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(
+                        Signal::$name => stringify!($value),
+                    )*
+                }
+            }
+
+            /// Returns the conventional name of the signal without the `SIG` prefix, e.g. `"INT"`.
+            ///
+            /// This is the form accepted by [`Signal::parse_kill_arg`]. Note that it differs from
+            /// the variant name accepted by [`Signal::from_short_name`] for some signals, e.g.
+            /// `"ALRM"` for [`Signal::Alarm`].
+            pub fn short_name(self) -> &'static str {
+                &self.name()[3..]
+            }
+
+            /// Parse a signal from its short name.
+            ///
+            /// The short name is the name of the enum variant, matched case-insensitively (e.g.
+            /// `"term"` or `"HUP"`). Names with a `SIG` prefix and signal numbers are rejected.
+            pub fn from_short_name(name: &str) -> Option<Self> {
+                $(
+                    if name.eq_ignore_ascii_case(stringify!($name)) {
+                        return Some(Signal::$name);
+                    }
+                )*
+
+                None
+            }
+
+            /// Parse a signal from its conventional name, without the `SIG` prefix.
+            fn from_unprefixed_name(name: &str) -> Option<Self> {
+                $(
+                    if name.eq_ignore_ascii_case(Signal::$name.short_name()) {
+                        return Some(Signal::$name);
+                    }
+                )*
+
+                None
+            }
         }
     }
 }
@@ -192,6 +267,9 @@ define_signal_enum! {
         /// `SIGINT`
         Int = SIGINT,
         /// `SIGQUIT`
+        ///
+        /// [`Signal::Break`] is an alias for this signal. On Windows, registering it listens for
+        /// `Ctrl+Break`.
         Quit = SIGQUIT,
         /// `SIGILL`
         Ill = SIGILL,
@@ -255,8 +333,251 @@ define_signal_enum! {
     }
 }
 
+impl Signal {
+    /// The signal sent by `Ctrl+Break`, aka `SIGQUIT`.
+    ///
+    /// Windows consoles distinguish `Ctrl+C` from `Ctrl+Break`. On Windows, registering this
+    /// signal listens for `CTRL_BREAK_EVENT`. Unix has no direct equivalent; the closest one is
+    /// `SIGQUIT`, which terminals send for `Ctrl+\`, so this is the same signal as
+    /// [`Signal::Quit`] on every platform.
+    ///
+    /// Since this is an alias rather than a separate variant, it can't be told apart from
+    /// [`Signal::Quit`]: its [`Debug`](fmt::Debug) output is `Quit`, and [`name`](Self::name),
+    /// [`describe`](Self::describe), numbers and conversions are those of `SIGQUIT`, on Windows
+    /// too.
+    #[allow(non_upper_case_globals)]
+    pub const Break: Signal = Signal::Quit;
+
+    /// Parse a signal in any of the forms accepted by `kill(1)`.
+    ///
+    /// This accepts a conventional signal name with or without the `SIG` prefix (e.g. `"TERM"` or
+    /// `"SIGTERM"`) or a signal number (e.g. `"15"`), optionally preceded by a single dash (e.g.
+    /// `"-TERM"` or `"-15"`). Names are matched case-insensitively.
+    pub fn parse_kill_arg(arg: &str) -> Option<Self> {
+        let arg = arg.strip_prefix('-').unwrap_or(arg);
+
+        // Signal numbers.
+        if !arg.is_empty() && arg.bytes().all(|b| b.is_ascii_digit()) {
+            return arg.parse().ok().and_then(Self::from_number);
+        }
+
+        // Signal names, with or without the prefix.
+        let name = match arg.get(..3) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("SIG") => &arg[3..],
+            _ => arg,
+        };
+        Self::from_unprefixed_name(name)
+    }
+
+    /// Tell whether this signal can be caught.
+    ///
+    /// `SIGKILL` and `SIGSTOP` can't be caught, so registering them in [`Signals`] fails.
+    pub fn can_catch(self) -> bool {
+        !matches!(self, Self::Kill | Self::Stop)
+    }
+
+    /// Iterate over every signal that can be caught.
+    ///
+    /// This is every signal except for `SIGKILL` and `SIGSTOP`; see [`Signal::can_catch`]. Note
+    /// that [`Signals`] also refuses `SIGILL`, `SIGFPE` and `SIGSEGV`, which can be caught but
+    /// can't be handled asynchronously.
+    pub fn iter_catchable() -> impl Iterator<Item = Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|signal| signal.can_catch())
+    }
+
+    /// Convert a signal number, such as one of the [`signal_hook::consts`], into a signal.
+    ///
+    /// Signals use the same numbers as `signal-hook`, so `signal as i32` can be passed to
+    /// `signal-hook` in turn. Returns `None` if the signal isn't supported.
+    ///
+    /// [`signal_hook::consts`]: https://docs.rs/signal-hook/latest/signal_hook/consts/index.html
+    pub fn from_signal_hook(number: i32) -> Option<Self> {
+        Self::from_number(number)
+    }
+
+    /// Iterate over the signals in a `sigset_t`, in order of their numbers.
+    ///
+    /// Signal numbers in the set that don't correspond to a [`Signal`] are skipped.
+    #[cfg(unix)]
+    pub fn iter_set(sigset: &libc::sigset_t) -> impl Iterator<Item = Self> + '_ {
+        (0..64)
+            .filter_map(Self::from_number)
+            .filter(move |signal| unsafe {
+                // SAFETY: `sigset` is a valid, initialized signal set.
+                libc::sigismember(sigset, signal.number()) == 1
+            })
+    }
+
+    /// Returns the exit code that shells use for a process killed by this signal.
+    ///
+    /// This is `128` plus the signal number, e.g. `130` for `SIGINT`.
+    pub fn exit_code(self) -> i32 {
+        128 + self.number()
+    }
+
+    /// Returns a short, human-readable description of the signal, e.g. `"Termination request"`
+    /// for `SIGTERM`.
+    ///
+    /// The descriptions are meant for display and follow the ones used by `strsignal(3)`, with
+    /// some hints about the conventional use of the signal.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Signal::Hup => "Hangup / reload",
+            Signal::Int => "Interrupt",
+            Signal::Quit => "Quit",
+            Signal::Ill => "Illegal instruction",
+            Signal::Trap => "Trace/breakpoint trap",
+            Signal::Abort => "Aborted",
+            Signal::Bus => "Bus error",
+            Signal::Fpe => "Floating point exception",
+            Signal::Kill => "Killed",
+            Signal::Usr1 => "User defined signal 1",
+            Signal::Segv => "Segmentation fault",
+            Signal::Usr2 => "User defined signal 2",
+            Signal::Pipe => "Broken pipe",
+            Signal::Alarm => "Alarm clock",
+            Signal::Term => "Termination request",
+            Signal::Child => "Child exited",
+            Signal::Cont => "Continued",
+            Signal::Stop => "Stopped (signal)",
+            Signal::Tstp => "Stopped (terminal)",
+            Signal::Ttin => "Stopped (terminal input)",
+            Signal::Ttou => "Stopped (terminal output)",
+            Signal::Urg => "Urgent I/O condition",
+            Signal::Xcpu => "CPU time limit exceeded",
+            Signal::Xfsz => "File size limit exceeded",
+            Signal::Vtalarm => "Virtual timer expired",
+            Signal::Prof => "Profiling timer expired",
+            Signal::Winch => "Window changed",
+            Signal::Io => "I/O possible",
+            Signal::Sys => "Bad system call",
+        }
+    }
+
+    /// Run the default action of this signal, as if no handler was registered for it.
+    ///
+    /// This is a typed wrapper around [`signal_hook::low_level::emulate_default_handler`], meant
+    /// to be called after a received signal has been handled. Depending on the signal, this may
+    /// terminate or stop the process, or do nothing at all.
+    ///
+    /// [`signal_hook::low_level::emulate_default_handler`]: https://docs.rs/signal-hook/latest/signal_hook/low_level/fn.emulate_default_handler.html
+    #[cfg(unix)]
+    pub fn handle_default(self) -> io::Result<()> {
+        signal_hook::low_level::emulate_default_handler(self.number())
+    }
+}
+
+/// Create an array of signals from their short names.
+///
+/// The short names are the names of the [`Signal`] variants, so a misspelled name is a compile
+/// error.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_signal::{signals, Signal, Signals};
+///
+/// assert_eq!(signals![Term, Int], [Signal::Term, Signal::Int]);
+///
+/// let signals = Signals::new(signals![Term, Int, Hup])?;
+/// # std::io::Result::Ok(())
+/// ```
+///
+/// A misspelled name doesn't compile:
+///
+/// ```compile_fail
+/// let _ = async_signal::signals![Term, Trem];
+/// ```
+#[macro_export]
+macro_rules! signals {
+    ($($name:ident),* $(,)?) => {
+        [$($crate::Signal::$name),*]
+    };
+}
+
+/// Convert a signal into its `nix` counterpart, failing with `EINVAL` if `nix` doesn't support it
+/// on this platform.
+#[cfg(all(unix, feature = "nix"))]
+impl std::convert::TryFrom<Signal> for nix::sys::signal::Signal {
+    type Error = nix::errno::Errno;
+
+    fn try_from(signal: Signal) -> Result<Self, Self::Error> {
+        Self::try_from(signal.number())
+    }
+}
+
+/// Convert a `nix` signal, failing for signals that aren't supported.
+#[cfg(all(unix, feature = "nix"))]
+impl std::convert::TryFrom<nix::sys::signal::Signal> for Signal {
+    type Error = io::Error;
+
+    fn try_from(signal: nix::sys::signal::Signal) -> io::Result<Self> {
+        Self::from_number(signal as _).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported signal {}", signal),
+            )
+        })
+    }
+}
+
+/// Compare a signal with a raw signal number, e.g. `signal == libc::SIGTERM`.
+impl PartialEq<std::os::raw::c_int> for Signal {
+    fn eq(&self, other: &std::os::raw::c_int) -> bool {
+        self.number() == *other
+    }
+}
+
+/// Compare a raw signal number with a signal, e.g. `libc::SIGTERM == signal`.
+impl PartialEq<Signal> for std::os::raw::c_int {
+    fn eq(&self, other: &Signal) -> bool {
+        *self == other.number()
+    }
+}
+
 /// Wait for a specific set of signals.
 ///
+/// Receiving signals is cancellation safe. A signal is only taken out of the internal pipe in the
+/// same poll that returns it, so dropping a pending `next()` future never loses a signal.
+///
+/// Signals are returned in the order in which they were received, whether they are taken one at a
+/// time or in batches. There are a few exceptions: [`Signals::set_priority`] and
+/// [`Signals::set_round_robin`] reorder signals that are available at the same time, and signals
+/// that did not fit into the internal pipe in [unbounded](Signals::set_unbounded) mode lose their
+/// relative order.
+///
+/// There is no thread affinity. On Unix, the signal handler runs on whichever thread the
+/// operating system picks among the threads that don't block the signal, and it only writes into
+/// the internal pipe; reading from the pipe happens on whichever thread polls the `Signals`.
+/// Neither of them depends on the signal mask of the polling thread, so no thread needs a
+/// particular mask. A signal is only lost if it is blocked in every thread, which
+/// [`Signals::delivery_is_reliable`] helps to detect.
+///
+/// `Signals` is `Send`, `Sync` and `'static`, so it can be moved into a spawned task and used as
+/// an owned stream there:
+///
+/// ```no_run
+/// use async_signal::{Signal, Signals};
+/// use futures_lite::prelude::*;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut signals = Signals::new([Signal::Term, Signal::Int])?;
+///
+/// let task = std::thread::spawn(move || {
+///     futures_lite::future::block_on(async move {
+///         while let Some(signal) = signals.next().await {
+///             println!("Received {:?}", signal?);
+///         }
+///         std::io::Result::Ok(())
+///     })
+/// });
+/// # Ok(())
+/// # }
+/// ```
+///
 /// See the [module-level documentation](index.html) for more details.
 pub struct Signals {
     /// The strategy used to read the signals.
@@ -264,6 +585,101 @@ pub struct Signals {
 
     /// The map between signal numbers and signal IDs.
     signal_ids: HashMap<Signal, SigId>,
+
+    /// The number of times each signal was received, if counting is enabled.
+    counts: Option<Mutex<HashMap<Signal, u64>>>,
+
+    /// Signals that have been read from the notifier but not yet returned.
+    queue: Mutex<VecDeque<Signal>>,
+
+    /// The order in which queued signals are returned, from highest to lowest priority.
+    priority: Vec<Signal>,
+
+    /// The last signal that was returned in round-robin mode, or `None` if it is disabled.
+    round_robin: Option<Mutex<Option<Signal>>>,
+
+    /// The signals whose deliveries are discarded.
+    disarmed: SignalSet,
+
+    /// The maximum number of signals that are taken at once.
+    batch_size: usize,
+
+    /// The number of signals that were dropped so far.
+    dropped: AtomicUsize,
+
+    /// The hook that is called with diagnostic events.
+    hook: Option<Box<DiagnosticHook>>,
+
+    /// The state shared with the control handles, once one has been created.
+    control: Mutex<Option<Arc<Control>>>,
+
+    /// The label of this instance, for diagnostics.
+    name: Option<Cow<'static, str>>,
+
+    /// The timer used by `poll_next_deadline`, along with its deadline.
+    #[cfg(unix)]
+    timer: Mutex<Option<(async_io::Timer, Instant)>>,
+}
+
+/// A hook that is called with the name of the `Signals` and a diagnostic event.
+type DiagnosticHook = dyn Fn(Option<&str>, Diagnostic) + Send + Sync + 'static;
+
+/// The kind of handler that is registered for a signal.
+#[derive(Debug, Clone, Copy)]
+enum Handler {
+    /// Only notify the `Signals`.
+    Plain,
+
+    /// Notify the `Signals`, then run the default action.
+    #[cfg(unix)]
+    Passthrough,
+
+    /// Notify the `Signals` the first time, then run the default action.
+    #[cfg(unix)]
+    Oneshot,
+}
+
+/// What happens to a signal that arrives while the internal pipe is full.
+///
+/// This is set through [`Signals::set_queue_full_policy`]. It has no effect in
+/// [unbounded](Signals::set_unbounded) mode, where the pipe is never full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QueueFullPolicy {
+    /// Drop the signal that just arrived.
+    #[default]
+    DropNewest,
+
+    /// Drop the oldest signal in the pipe to make room for the one that just arrived.
+    DropOldest,
+
+    /// Wait until there is room in the pipe.
+    ///
+    /// Signal handlers can't wait, so this policy is not supported and is rejected by
+    /// [`Signals::set_queue_full_policy`].
+    Block,
+}
+
+/// A diagnostic event, passed to the hook set by [`Signals::set_diagnostic_hook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Diagnostic {
+    /// A signal was dropped because the internal pipe was full.
+    Dropped(Signal),
+
+    /// An error occurred while reading a signal.
+    ReadError(io::ErrorKind),
+
+    /// A signal already had a handler that wasn't installed by this crate when it was added.
+    ///
+    /// Any handler other than `SIG_DFL` and `SIG_IGN` counts, unless this crate installed it. This
+    /// includes handlers that other crates registered through `signal-hook-registry` directly,
+    /// even though those are chained correctly, since the crate can't tell them apart from a
+    /// handler installed with `sigaction`. The existing handler keeps being called, but it may
+    /// not expect the signal to be handled elsewhere too.
+    ///
+    /// This is only detected on Unix, and only in debug builds.
+    ConflictingHandler(Signal),
 }
 
 impl Drop for Signals {
@@ -271,6 +687,11 @@ impl Drop for Signals {
         for signal in self.signal_ids.values() {
             registry::unregister(*signal);
         }
+
+        // Detach the control handles, so that they don't outlive the notifier.
+        if let Some(control) = self.control.get_mut().unwrap_or_else(|e| e.into_inner()) {
+            *control.inject.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        }
     }
 }
 
@@ -285,6 +706,7 @@ impl fmt::Debug for Signals {
         }
 
         f.debug_struct("Signals")
+            .field("name", &self.name)
             .field("notifier", &self.notifier)
             .field("signal_ids", &RegisteredSignals(&self.signal_ids))
             .finish()
@@ -293,14 +715,16 @@ impl fmt::Debug for Signals {
 
 impl Signals {
     /// Create a new `Signals` instance with a set of signals.
+    ///
+    /// This is a shortcut for using [`SignalsBuilder`] with the default options.
+    ///
+    /// On Unix, this fails with `EMFILE` or `ENFILE` if there are no file descriptors left for
+    /// the signal pipe.
     pub fn new<B>(signals: impl IntoIterator<Item = B>) -> io::Result<Self>
     where
         B: Borrow<Signal>,
     {
-        let mut this = Self {
-            notifier: sys::Notifier::new()?,
-            signal_ids: HashMap::new(),
-        };
+        let mut this = Self::new_empty()?;
 
         // Add the signals to the set of signals to wait for.
         this.add_signals(signals)?;
@@ -308,6 +732,134 @@ impl Signals {
         Ok(this)
     }
 
+    /// Create a new `Signals` instance with the signals in a `sigset_t`.
+    ///
+    /// Signal numbers in the set that don't correspond to a [`Signal`] are ignored.
+    #[cfg(unix)]
+    pub fn from_sigset(sigset: &libc::sigset_t) -> io::Result<Self> {
+        Self::new(SignalSet::from(sigset))
+    }
+
+    /// Create a new `Signals` instance with the signals listed in an environment variable.
+    ///
+    /// The variable holds a comma-separated list of signals in any of the forms accepted by
+    /// [`Signal::parse_kill_arg`], e.g. `APP_SIGNALS=TERM,INT,HUP`. Whitespace around the entries
+    /// and empty entries are ignored, so an empty variable results in an empty `Signals`.
+    ///
+    /// Returns an error of kind [`NotFound`](io::ErrorKind::NotFound) if the variable isn't set,
+    /// and of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if it contains an unknown signal.
+    pub fn from_env(var_name: &str) -> io::Result<Self> {
+        let value = std::env::var(var_name).map_err(|err| {
+            let kind = match err {
+                std::env::VarError::NotPresent => io::ErrorKind::NotFound,
+                std::env::VarError::NotUnicode(_) => io::ErrorKind::InvalidData,
+            };
+            io::Error::new(kind, format!("{}: {}", var_name, err))
+        })?;
+
+        let signals = value
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                Signal::parse_kill_arg(name).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("{}: unknown signal `{}`", var_name, name),
+                    )
+                })
+            })
+            .collect::<io::Result<SignalSet>>()?;
+
+        Self::new(signals)
+    }
+
+    /// Create a builder for configuring a new `Signals` instance.
+    pub fn builder() -> SignalsBuilder {
+        SignalsBuilder::new()
+    }
+
+    /// Create a new `Signals` instance without any signals.
+    ///
+    /// Signals can be added later using [`add_signals`](Self::add_signals). Until then, polling
+    /// this instance will always return [`Poll::Pending`].
+    pub fn new_empty() -> io::Result<Self> {
+        Ok(Self {
+            notifier: sys::Notifier::new()?,
+            signal_ids: HashMap::new(),
+            counts: None,
+            queue: Mutex::new(VecDeque::new()),
+            priority: Vec::new(),
+            round_robin: None,
+            disarmed: SignalSet::new(),
+            batch_size: usize::MAX,
+            dropped: AtomicUsize::new(0),
+            hook: None,
+            control: Mutex::new(None),
+            name: None,
+            #[cfg(unix)]
+            timer: Mutex::new(None),
+        })
+    }
+
+    /// Deliver a signal as if it had been received, without sending an actual signal.
+    ///
+    /// This wakes up any task that is waiting for a signal. The signal goes through the same path
+    /// as received signals, so it is subject to the capacity and coalescing settings, and it is
+    /// only delivered while it is registered.
+    pub fn inject(&self, signal: Signal) {
+        self.notifier.inject(signal);
+    }
+
+    /// Get a handle that injects signals into this `Signals` from elsewhere.
+    ///
+    /// Unlike the `Signals` itself, the handle can't be used to receive signals, so the task that
+    /// consumes the signals keeps sole ownership of them. The handle is cheap to clone and can be
+    /// sent to other tasks or threads. Once this `Signals` is dropped, the handle does nothing.
+    pub fn control_handle(&self) -> io::Result<SignalsControl> {
+        let mut control = self.control.lock().unwrap_or_else(|e| e.into_inner());
+
+        let control = match &*control {
+            Some(control) => control.clone(),
+            None => control
+                .insert(Arc::new(Control {
+                    inject: Mutex::new(Some(Box::new(self.notifier.injector()?))),
+                }))
+                .clone(),
+        };
+
+        Ok(SignalsControl { control })
+    }
+
+    /// Attach a label to this `Signals`, for diagnostics.
+    ///
+    /// This tells instances apart in a process that has several of them. The label appears in
+    /// the [`Debug`](fmt::Debug) output, can be retrieved with [`name`](Self::name), and is passed
+    /// to a diagnostic hook set through
+    /// [`set_named_diagnostic_hook`](Self::set_named_diagnostic_hook).
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Get the label attached through [`with_name`](Self::with_name), if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Deliver one synthetic occurrence of a signal before any real one.
+    ///
+    /// This is useful when a signal means "reload", so that the initial load can go through the
+    /// same code path as later reloads. The synthetic signal was not sent by any process. Like
+    /// any other signal, it is only delivered while it is registered.
+    pub fn with_initial_tick(self, signal: Signal) -> Self {
+        self.queue
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push_front(signal);
+        self
+    }
+
     /// Add signals to the set of signals to wait for.
     ///
     /// One signal cannot be added twice. If a signal that has already been added is passed to this
@@ -318,31 +870,257 @@ impl Signals {
     /// To run the default signal handler, use [`signal_hook::low_level::emulate_default_handler`]
     /// instead.
     ///
+    /// A signal is only caught once its handler has been installed, which happens in this
+    /// method. Every delivery of the signal after this method returns is captured, but a signal
+    /// that was delivered before, e.g. between [`new_empty`](Self::new_empty) and this call, is
+    /// handled by whatever disposition was in place at the time and is not returned later. To
+    /// keep a signal from being lost at startup, add it as early as possible.
+    ///
+    /// If one of the signals can't be registered, the signals that were added by this call are
+    /// removed again before the error is returned, so the set of registered signals is left
+    /// unchanged.
+    ///
     /// [`signal_hook::low_level::emulate_default_handler`]: https://docs.rs/signal-hook/latest/signal_hook/low_level/fn.emulate_default_handler.html
     pub fn add_signals<B>(&mut self, signals: impl IntoIterator<Item = B>) -> io::Result<()>
     where
         B: Borrow<Signal>,
     {
+        self.add_signals_inner(signals, Handler::Plain)
+    }
+
+    /// Add signals to the set of signals to wait for, reporting the result for each signal.
+    ///
+    /// Unlike [`add_signals`](Self::add_signals), this doesn't stop at the first signal that
+    /// can't be registered. Together with [`Signals::new_empty`], this separates creating the
+    /// notifier from registering the signals.
+    pub fn add_signals_each<B>(
+        &mut self,
+        signals: impl IntoIterator<Item = B>,
+    ) -> Vec<(Signal, io::Result<()>)>
+    where
+        B: Borrow<Signal>,
+    {
+        signals
+            .into_iter()
+            .map(|signal| {
+                let signal = *signal.borrow();
+                (signal, self.add_signal(signal, Handler::Plain))
+            })
+            .collect()
+    }
+
+    /// Add signals to the set of signals to wait for, while still running their default behavior.
+    ///
+    /// This works like [`add_signals`](Self::add_signals), except that once a signal has been
+    /// queued for this `Signals`, the signal handler also calls
+    /// [`signal_hook::low_level::emulate_default_handler`] for it.
+    ///
+    /// This is only meaningful for signals that have a default action other than being ignored.
+    /// Note the sharp edges:
+    ///
+    /// - Signals that terminate the process by default (e.g. `SIGTERM`) will terminate it right
+    ///   after being queued, so the notification will likely never be observed.
+    /// - Signals that stop the process by default (`SIGTSTP`, `SIGTTIN` and `SIGTTOU`) will stop it
+    ///   right after being queued. The notification is observed once the process is continued.
+    /// - Signals that are ignored by default (e.g. `SIGCHLD` or `SIGWINCH`) behave exactly as if
+    ///   they were added through [`add_signals`](Self::add_signals).
+    ///
+    /// The default behavior is emulated for every delivery of the signal to the process, even if
+    /// it was also registered through [`add_signals`](Self::add_signals) by another `Signals`.
+    ///
+    /// [`signal_hook::low_level::emulate_default_handler`]: https://docs.rs/signal-hook/latest/signal_hook/low_level/fn.emulate_default_handler.html
+    #[cfg(unix)]
+    pub fn add_passthrough_signals<B>(
+        &mut self,
+        signals: impl IntoIterator<Item = B>,
+    ) -> io::Result<()>
+    where
+        B: Borrow<Signal>,
+    {
+        self.add_signals_inner(signals, Handler::Passthrough)
+    }
+
+    /// Add a signal that is only caught once.
+    ///
+    /// The first delivery of the signal is queued for this `Signals` like it would be for
+    /// [`add_signals`](Self::add_signals). Every delivery after that runs the default action of
+    /// the signal instead, as if the handler had been uninstalled. This is the classic "the first
+    /// Ctrl-C shuts down gracefully, the second one kills":
+    ///
+    /// ```no_run
+    /// use async_signal::{Signal, Signals};
+    /// use futures_lite::prelude::*;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut signals = Signals::new_empty()?;
+    /// signals.add_oneshot(Signal::Int)?;
+    ///
+    /// signals.next().await.unwrap()?;
+    /// println!("Shutting down, press Ctrl-C again to force it.");
+    /// # std::io::Result::Ok(())
+    /// # });
+    /// ```
+    ///
+    /// The default action is emulated through
+    /// [`signal_hook::low_level::emulate_default_handler`], with the same caveats as for
+    /// [`add_passthrough_signals`](Self::add_passthrough_signals). If the signal is already
+    /// registered by this `Signals`, this does nothing. The signal stays registered until it is
+    /// removed through [`remove_signals`](Self::remove_signals) or this `Signals` is dropped.
+    ///
+    /// [`signal_hook::low_level::emulate_default_handler`]: https://docs.rs/signal-hook/latest/signal_hook/low_level/fn.emulate_default_handler.html
+    #[cfg(unix)]
+    pub fn add_oneshot(&mut self, signal: Signal) -> io::Result<()> {
+        self.add_signal(signal, Handler::Oneshot)
+    }
+
+    /// Add signals to the set of signals to wait for, without blocking the current task.
+    ///
+    /// This works like [`add_signals`](Self::add_signals), except that the signals are registered
+    /// on the [`blocking`] thread pool. Registering a signal takes a global lock, so this keeps a
+    /// latency-sensitive task from being stalled by other registrations.
+    ///
+    /// If registering a signal fails, none of the signals passed to this call stay registered.
+    /// The same happens if the future is dropped before it completes: the handlers that were
+    /// registered so far are unregistered again.
+    ///
+    /// [`blocking`]: https://docs.rs/blocking
+    #[cfg(feature = "blocking")]
+    pub async fn add_signals_async<B>(
+        &mut self,
+        signals: impl IntoIterator<Item = B>,
+    ) -> io::Result<()>
+    where
+        B: Borrow<Signal>,
+    {
+        // Get the closures for the signals we haven't registered yet.
+        let mut closures = Vec::new();
         for signal in signals {
-            let signal = signal.borrow();
+            let signal = *signal.borrow();
 
-            // If we've already registered this signal, skip it.
-            if self.signal_ids.contains_key(signal) {
+            if self.signal_ids.contains_key(&signal) || closures.iter().any(|(s, _)| *s == signal) {
                 continue;
             }
 
-            // Get the closure to call when the signal is received.
-            let closure = self.notifier.add_signal(*signal)?;
+            check_forbidden(signal)?;
+            self.check_conflict(signal);
+            closures.push((signal, self.notifier.add_signal(signal)?));
+        }
+
+        // Keep track of the handlers outside of the thread pool, so that they can be unregistered
+        // if this future is dropped.
+        let pending = PendingRegistration {
+            signals: closures.iter().map(|(signal, _)| *signal).collect(),
+            ids: Arc::new(Mutex::new(Some(Vec::with_capacity(closures.len())))),
+            owner: self,
+        };
+
+        // Register them on the thread pool.
+        let ids = pending.ids.clone();
+        blocking::unblock(move || {
+            for (signal, closure) in closures {
+                let mut ids = ids.lock().unwrap_or_else(|e| e.into_inner());
+
+                // Stop if the future was dropped in the meantime.
+                let ids = match ids.as_mut() {
+                    Some(ids) => ids,
+                    None => return Ok(()),
+                };
 
-            let id = unsafe {
                 // SAFETY: Closure is guaranteed to be signal-safe.
-                registry::register(signal.number(), closure)?
-            };
+                match unsafe { registry::register(signal.number(), closure) } {
+                    Ok(id) => ids.push((signal, id)),
+                    Err(e) => {
+                        // Leave the set of signals as it was before this call.
+                        for (_, id) in ids.drain(..) {
+                            registry::unregister(id);
+                        }
+                        return Err(e);
+                    }
+                }
+            }
+
+            Ok(())
+        })
+        .await?;
+
+        pending.finish();
+        Ok(())
+    }
+
+    /// Add signals to the set of signals to wait for, optionally emulating the default handler.
+    fn add_signals_inner<B>(
+        &mut self,
+        signals: impl IntoIterator<Item = B>,
+        handler: Handler,
+    ) -> io::Result<()>
+    where
+        B: Borrow<Signal>,
+    {
+        let mut added = SignalSet::new();
+
+        for signal in signals {
+            let signal = *signal.borrow();
+            if self.signal_ids.contains_key(&signal) {
+                continue;
+            }
+
+            if let Err(err) = self.add_signal(signal, handler) {
+                // Leave the set of signals as it was before this call.
+                self.remove_signals(added)?;
+                return Err(err);
+            }
+            added.insert(signal);
+        }
 
-            // Add the signal ID to the map.
-            self.signal_ids.insert(*signal, id);
+        Ok(())
+    }
+
+    /// Register a single signal.
+    fn add_signal(&mut self, signal: Signal, handler: Handler) -> io::Result<()> {
+        // If we've already registered this signal, skip it.
+        if self.signal_ids.contains_key(&signal) {
+            return Ok(());
         }
 
+        check_forbidden(signal)?;
+        self.check_conflict(signal);
+
+        // Get the closure to call when the signal is received.
+        let closure = self.notifier.add_signal(signal)?;
+
+        let id = unsafe {
+            // SAFETY: Closure is guaranteed to be signal-safe.
+            match handler {
+                Handler::Plain => registry::register(signal.number(), closure)?,
+                #[cfg(unix)]
+                Handler::Passthrough => {
+                    let number = signal.number();
+                    registry::register(number, move || {
+                        closure();
+
+                        // SAFETY: emulate_default_handler() is signal safe.
+                        let _ = signal_hook::low_level::emulate_default_handler(number);
+                    })?
+                }
+                #[cfg(unix)]
+                Handler::Oneshot => {
+                    let number = signal.number();
+                    let fired = std::sync::atomic::AtomicBool::new(false);
+                    registry::register(number, move || {
+                        if fired.swap(true, Ordering::SeqCst) {
+                            // SAFETY: emulate_default_handler() is signal safe.
+                            let _ = signal_hook::low_level::emulate_default_handler(number);
+                        } else {
+                            closure();
+                        }
+                    })?
+                }
+            }
+        };
+
+        // Add the signal ID to the map.
+        self.signal_ids.insert(signal, id);
+        mark_installed(signal);
         Ok(())
     }
 
@@ -351,6 +1129,11 @@ impl Signals {
     /// This function can be used to opt out of listening to signals previously registered via
     /// [`add_signals`](Self::add_signals) or [`new`](Self::new). If a signal that has not been
     /// registered is passed to this method, it will be ignored.
+    ///
+    /// Once this method returns, the removed signals will no longer be returned by this
+    /// `Signals`. The signal handler is unregistered first, which waits for any running instance
+    /// of it to finish. Any notification that was queued before that is then discarded when it is
+    /// read, unless the signal has been added again by then.
     pub fn remove_signals<B>(&mut self, signals: impl IntoIterator<Item = B>) -> io::Result<()>
     where
         B: Borrow<Signal>,
@@ -364,35 +1147,1101 @@ impl Signals {
                 None => continue,
             };
 
+            // Use `signal-hook-registry` to unregister the signal. Once this returns, the handler
+            // is no longer running.
+            registry::unregister(id);
+
             // Remove the signal from the notifier.
             self.notifier.remove_signal(*signal)?;
-
-            // Use `signal-hook-registry` to unregister the signal.
-            registry::unregister(id);
         }
 
         Ok(())
     }
-}
 
-#[cfg(unix)]
-impl AsRawFd for Signals {
-    fn as_raw_fd(&self) -> RawFd {
-        self.notifier.as_raw_fd()
+    /// Get a [`Sink`] that removes the signals that are sent into it.
+    ///
+    /// This makes it possible to drive removals from a stream of commands, e.g. with
+    /// `StreamExt::forward`. Removing a signal never waits, so the sink is always ready and
+    /// flushing does nothing; each signal is removed as soon as it is sent, as if by
+    /// [`remove_signals`](Self::remove_signals). Sending a signal that isn't registered does
+    /// nothing.
+    ///
+    /// [`Sink`]: https://docs.rs/futures-sink/latest/futures_sink/trait.Sink.html
+    #[cfg(feature = "futures-sink")]
+    pub fn remover(&mut self) -> Remover<'_> {
+        Remover { signals: self }
     }
-}
 
-#[cfg(unix)]
-impl AsFd for Signals {
-    fn as_fd(&self) -> BorrowedFd<'_> {
-        self.notifier.as_fd()
-    }
-}
+    /// Take all of the signals that are available right now, without waiting.
+    ///
+    /// This is meant for places where awaiting isn't possible, such as `Drop` implementations or
+    /// panic handlers. Unlike [`Signals::shutdown`], the signals stay registered. If an error
+    /// occurs, the signals that were taken before it are returned.
+    pub fn drain_to_vec(&self) -> Vec<Signal> {
+        let mut signals = Vec::new();
 
-impl Unpin for Signals {}
+        while let Ok(Some(signal)) = self.try_signal() {
+            signals.push(signal);
+        }
 
-impl Stream for Signals {
-    type Item = io::Result<Signal>;
+        signals
+    }
+
+    /// Move every signal that is waiting in the internal pipe into the internal queue.
+    ///
+    /// This takes a snapshot of the signals that are available right now and keeps them in
+    /// memory, e.g. before an operation that invalidates file descriptors such as `fork()`. The
+    /// signals are not consumed; they are returned by later reads, in the same order. Signals
+    /// that arrive afterwards go through the pipe as usual.
+    ///
+    /// Returns the number of signals that were moved.
+    pub fn drain_fd_into_queue(&self) -> io::Result<usize> {
+        let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+        let mut moved = 0;
+
+        while let Some(signal) = self.notifier.try_next().map_err(|e| self.report(e))? {
+            queue.push_back(signal);
+            moved += 1;
+        }
+
+        Ok(moved)
+    }
+
+    /// Call a closure every time a signal is received, e.g. to reload the configuration on
+    /// `SIGHUP`.
+    ///
+    /// The returned future runs until an error occurs. If the closure returns an error, it is
+    /// returned without waiting for further signals, so a failed reload can be logged or acted
+    /// upon by the caller; keep calling this method to carry on afterwards. While this runs, it
+    /// takes every signal from this `Signals`, so other signals are lost rather than left for
+    /// another task.
+    pub async fn reload_on(
+        &self,
+        signal: Signal,
+        mut reload: impl FnMut() -> io::Result<()>,
+    ) -> io::Result<()> {
+        loop {
+            self.wait_for_any(&[signal]).await?;
+            reload()?;
+        }
+    }
+
+    /// Wait for a signal, run a closure, then run the default action of the signal.
+    ///
+    /// This packages the common "clean up on `SIGTERM`, then terminate like the default handler
+    /// would" pattern, so that the exit status of the process reports the signal. The signal must
+    /// be registered by this `Signals`. Signals other than `signal` that arrive before it are
+    /// thrown away.
+    ///
+    /// For signals that terminate the process by default, this doesn't return. See
+    /// [`Signal::handle_default`] for how the default action is run.
+    #[cfg(unix)]
+    pub async fn on_then_default(&self, signal: Signal, cleanup: impl FnOnce()) -> io::Result<()> {
+        self.wait_for_any(&[signal]).await?;
+        cleanup();
+        signal.handle_default()
+    }
+
+    /// Observe the signals that this `Signals` receives, without consuming them.
+    ///
+    /// The returned stream is a fan-out view: it receives its own copy of every signal that is
+    /// registered here at the time of the call, regardless of whether this `Signals` is polled,
+    /// and taking signals from it doesn't affect this `Signals`. Signals that are added or removed
+    /// afterwards aren't reflected in the observer.
+    ///
+    /// This registers a separate set of handlers, so it can fail like [`Signals::new`]. The
+    /// observer never drops signals. If an error occurs while receiving, the stream ends.
+    pub fn observe(&self) -> io::Result<Observer> {
+        let mut signals = Signals::new_empty()?;
+        signals.set_unbounded(true);
+        signals.add_signals(self.signal_ids.keys())?;
+
+        Ok(Observer {
+            signals,
+            terminated: false,
+        })
+    }
+
+    /// Get a stream of signals along with the time at which they were received.
+    ///
+    /// The time is taken when the signal is taken out of the internal pipe, since the signal
+    /// handler can't safely read the clock. It therefore reflects when the signal was observed,
+    /// not when it was delivered; if the stream isn't polled for a while, the two can be far
+    /// apart.
+    pub fn timestamped(&self) -> Timestamped<'_> {
+        Timestamped { signals: self }
+    }
+
+    /// Get a stream of signals along with the number of occurrences that each one stands for.
+    ///
+    /// With [coalescing](Self::set_coalescing) enabled, a single received signal may stand for
+    /// several deliveries of it. The count includes the signal itself and every occurrence that
+    /// was merged into it, so it is always at least one; without coalescing, it is always one.
+    ///
+    /// Occurrences that arrive while a signal is being taken may be attributed to it rather than
+    /// to the next one. Every occurrence is still counted exactly once.
+    pub fn occurrences(&self) -> Occurrences<'_> {
+        Occurrences { signals: self }
+    }
+
+    /// Get a stream that emits a signal once a burst of it has settled.
+    ///
+    /// Every occurrence of `signal` restarts the debounce window, and the signal is emitted once
+    /// no occurrence has arrived for `window`. A rapid burst of `SIGHUP` therefore results in a
+    /// single emission, shortly after the last one. Unlike [coalescing](Self::set_coalescing),
+    /// this is based on time rather than on whether the signal is still waiting to be received.
+    ///
+    /// The stream reads from this `Signals` like any other consumer, so other signals that it
+    /// comes across are skipped and won't be seen elsewhere.
+    #[cfg(unix)]
+    pub fn debounced(&self, signal: Signal, window: std::time::Duration) -> Debounced<'_> {
+        Debounced {
+            signals: self,
+            signal,
+            window,
+            timer: None,
+        }
+    }
+
+    /// Unregister all signals and return the ones that were received but not consumed yet.
+    ///
+    /// Unlike dropping the `Signals`, this lets signals such as a pending `SIGTERM` be handled
+    /// during teardown. The signals are unregistered before the queue is drained, so no signal
+    /// that arrives in the meantime is lost.
+    pub async fn shutdown(mut self) -> io::Result<Vec<Signal>> {
+        for id in self.signal_ids.values() {
+            registry::unregister(*id);
+        }
+
+        let mut pending = Vec::new();
+        while let Some(signal) = self.try_signal()? {
+            pending.push(signal);
+        }
+
+        // Everything is unregistered already, so there is nothing left for `Drop` to do.
+        self.signal_ids.clear();
+        Ok(pending)
+    }
+
+    /// Forward every received signal into a channel.
+    ///
+    /// The returned future runs until the channel is closed, which is noticed the next time a
+    /// signal is received, or until an error occurs.
+    #[cfg(feature = "async-channel")]
+    pub async fn forward_to(self, tx: async_channel::Sender<Signal>) -> io::Result<()> {
+        loop {
+            let signal = NextSignal(&self).await?;
+
+            if tx.send(signal).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Wait for one of the given signals to be received.
+    ///
+    /// Returns the signal that was received. Any other signal that is received in the meantime is
+    /// consumed and discarded.
+    pub async fn wait_for_any(&self, signals: &[Signal]) -> io::Result<Signal> {
+        loop {
+            let signal = NextSignal(self).await?;
+            if signals.contains(&signal) {
+                return Ok(signal);
+            }
+        }
+    }
+
+    /// Wait until a signal has been received a number of times.
+    ///
+    /// This is useful for patterns like "press Ctrl-C twice to force quit". Other signals don't
+    /// count towards `count`, and are dropped until it is reached.
+    pub async fn wait_for_n(&self, signal: Signal, count: usize) -> io::Result<()> {
+        for _ in 0..count {
+            self.wait_for_any(&[signal]).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the [`SigId`] that `signal-hook-registry` returned when registering a signal.
+    ///
+    /// Returns `None` if the signal isn't registered.
+    ///
+    /// This is useful when also using [`signal_hook_registry`] directly. If the action is
+    /// unregistered through it, this `Signals` no longer receives the signal, even though it is
+    /// still counted as registered. Unregistering it again when the `Signals` is dropped or the
+    /// signal is removed is harmless.
+    ///
+    /// [`SigId`]: signal_hook_registry::SigId
+    #[cfg(unix)]
+    pub fn sig_id(&self, signal: Signal) -> Option<SigId> {
+        self.signal_ids.get(&signal).copied()
+    }
+
+    /// Tell whether the registered signals can be delivered to the current thread.
+    ///
+    /// The signal handlers only run in threads that don't block the signal, so a signal that is
+    /// blocked in every thread is never received. This checks the signal mask of the calling
+    /// thread only; other threads may still block or unblock the signals independently. It
+    /// returns `false` if any of the registered signals is blocked in the calling thread.
+    #[cfg(unix)]
+    pub fn delivery_is_reliable(&self) -> bool {
+        let mut mask = std::mem::MaybeUninit::<libc::sigset_t>::uninit();
+
+        // SAFETY: A null set only queries the current mask.
+        let mask = unsafe {
+            if libc::pthread_sigmask(libc::SIG_BLOCK, std::ptr::null(), mask.as_mut_ptr()) != 0 {
+                return false;
+            }
+            mask.assume_init()
+        };
+
+        let blocked = SignalSet::from(&mask);
+        self.signal_ids
+            .keys()
+            .all(|signal| !blocked.contains(*signal))
+    }
+
+    /// Get the registered signals that are pending at the operating system level.
+    ///
+    /// A signal is pending if it was sent but couldn't be delivered yet, because it is blocked in
+    /// the calling thread or in every thread of the process. This is what `sigpending` reports,
+    /// restricted to the signals registered by this `Signals`. It helps to find out why a signal
+    /// that was sent has not been received.
+    #[cfg(unix)]
+    pub fn os_pending(&self) -> io::Result<SignalSet> {
+        let mut pending = std::mem::MaybeUninit::<libc::sigset_t>::uninit();
+
+        // SAFETY: `pending` is writable.
+        let pending = unsafe {
+            if libc::sigpending(pending.as_mut_ptr()) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            pending.assume_init()
+        };
+
+        Ok(Signal::iter_set(&pending)
+            .filter(|signal| self.signal_ids.contains_key(signal))
+            .collect())
+    }
+
+    /// Stop delivering signals until [`Signals::resume_delivery`] is called.
+    ///
+    /// Signals that arrive while paused are held back rather than lost. Like the kernel's set of
+    /// pending signals, only one occurrence of each signal is held; a signal that arrives several
+    /// times while paused is delivered once on resume. Signals that were received before pausing
+    /// can still be taken.
+    ///
+    /// The signal handlers stay registered while paused, so this is cheaper than removing and
+    /// re-adding the signals.
+    #[cfg(unix)]
+    pub fn pause_delivery(&self) {
+        self.notifier.pause();
+    }
+
+    /// Resume delivering signals after [`Signals::pause_delivery`].
+    ///
+    /// The signals that were held back while paused are delivered first.
+    #[cfg(unix)]
+    pub fn resume_delivery(&self) {
+        self.notifier.resume();
+    }
+
+    /// Get the number of signals that are currently registered.
+    pub fn len(&self) -> usize {
+        self.signal_ids.len()
+    }
+
+    /// Tell whether no signals are currently registered.
+    pub fn is_empty(&self) -> bool {
+        self.signal_ids.is_empty()
+    }
+
+    /// Poll for the next signal, giving up once a deadline has passed.
+    ///
+    /// Returns `Ready(Ok(None))` once `deadline` has passed without a signal. This is meant for
+    /// hand-written futures and state machines; the timer is kept in this `Signals` and reused
+    /// across polls instead of being created anew. Only the waker of the latest call is woken,
+    /// like for [`Stream::poll_next`].
+    #[cfg(unix)]
+    pub fn poll_next_deadline(
+        &self,
+        cx: &mut Context<'_>,
+        deadline: Instant,
+    ) -> Poll<io::Result<Option<Signal>>> {
+        if let Poll::Ready(res) = self.poll_signal(cx) {
+            return Poll::Ready(res.map(Some));
+        }
+
+        let mut timer = self.timer.lock().unwrap_or_else(|e| e.into_inner());
+        let (timer, timer_deadline) =
+            timer.get_or_insert_with(|| (async_io::Timer::at(deadline), deadline));
+
+        // Only touch the reactor if the deadline changed.
+        if *timer_deadline != deadline {
+            timer.set_at(deadline);
+            *timer_deadline = deadline;
+        }
+
+        match Pin::new(timer).poll(cx) {
+            Poll::Ready(_) => Poll::Ready(Ok(None)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    /// Poll for up to `max` signals at once.
+    ///
+    /// This waits until at least one signal is available, and then moves every signal that is
+    /// immediately available (up to `max`) into `buf`. Returns the number of signals that were
+    /// added to `buf`.
+    pub fn poll_next_batch(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &mut Vec<Signal>,
+        max: usize,
+    ) -> Poll<io::Result<usize>> {
+        if max == 0 {
+            return Poll::Ready(Ok(0));
+        }
+        let max = max.min(self.batch_size);
+
+        // Wait for the first signal.
+        let signal = ready!(self.poll_signal(cx))?;
+        buf.push(signal);
+        let mut count = 1;
+
+        // Take any other signals that are already available. If an error occurs here, it will be
+        // returned on the next poll instead.
+        while count < max {
+            match self.try_signal() {
+                Ok(Some(signal)) => {
+                    buf.push(signal);
+                    count += 1;
+                }
+                _ => break,
+            }
+        }
+
+        Poll::Ready(Ok(count))
+    }
+
+    /// Wait for signals, then take all of the ones that are available.
+    ///
+    /// This waits until at least one signal is available, then returns it together with every
+    /// other signal that can be taken without waiting. If an error occurs after the first signal
+    /// was taken, it will be returned on the next call instead.
+    pub async fn recv_all(&self) -> io::Result<Vec<Signal>> {
+        let mut signals = vec![NextSignal(self).await?];
+
+        while signals.len() < self.batch_size {
+            match self.try_signal() {
+                Ok(Some(signal)) => signals.push(signal),
+                _ => break,
+            }
+        }
+
+        Ok(signals)
+    }
+
+    /// Set the order in which signals that are received at the same time are returned.
+    ///
+    /// Signals that come first in `order` are returned first. Signals that are not in `order` are
+    /// returned after all of the ones that are, in the order they were received. Passing an empty
+    /// `order` returns all signals in the order they were received, which is the default.
+    ///
+    /// When an order is set, every signal that is available is moved into an internal queue when
+    /// polling, so that the signal with the highest priority can be picked. This means that the
+    /// underlying file descriptor may not be readable even though signals are still available; use
+    /// [`try_next_signal`](Self::try_next_signal) until it returns `None` to drain them.
+    pub fn set_priority<B>(&mut self, order: impl IntoIterator<Item = B>)
+    where
+        B: Borrow<Signal>,
+    {
+        self.priority.clear();
+
+        for signal in order {
+            let signal = *signal.borrow();
+            if !self.priority.contains(&signal) {
+                self.priority.push(signal);
+            }
+        }
+    }
+
+    /// Set whether queued signals are returned in turns, one signal type at a time.
+    ///
+    /// By default, signals are returned in the order they were received, so a flood of one signal
+    /// can hold back the others. In round-robin mode, every signal that is available is moved into
+    /// an internal queue when polling, like for [`set_priority`](Self::set_priority), and the
+    /// signal types that have pending occurrences take turns: a signal is never returned twice in
+    /// a row while another signal is waiting. Occurrences of the same signal are returned in the
+    /// order they were received.
+    ///
+    /// A [priority order](Self::set_priority) takes precedence over this mode.
+    pub fn set_round_robin(&mut self, round_robin: bool) {
+        self.round_robin = match round_robin {
+            true => Some(self.round_robin.take().unwrap_or_default()),
+            false => None,
+        };
+    }
+
+    /// Set a hook that is called with diagnostic events.
+    ///
+    /// The hook is called for every signal that was dropped because the internal pipe was full
+    /// (see [`set_unbounded`](Self::set_unbounded)), and for every error that occurs while reading
+    /// a signal. It is never called from the signal handler; dropped signals are counted there and
+    /// reported the next time this `Signals` is polled.
+    pub fn set_diagnostic_hook(&mut self, hook: impl Fn(Diagnostic) + Send + Sync + 'static) {
+        self.hook = Some(Box::new(move |_, event| hook(event)));
+    }
+
+    /// Set a hook that is called with diagnostic events, along with the name of this `Signals`.
+    ///
+    /// This works like [`set_diagnostic_hook`](Self::set_diagnostic_hook), but the hook also
+    /// receives the label attached through [`with_name`](Self::with_name), so that one hook can
+    /// tell apart the events of several instances.
+    pub fn set_named_diagnostic_hook(
+        &mut self,
+        hook: impl Fn(Option<&str>, Diagnostic) + Send + Sync + 'static,
+    ) {
+        self.hook = Some(Box::new(hook));
+    }
+
+    /// Set whether signals should be kept when the internal pipe is full.
+    ///
+    /// By default, signals that arrive while the internal pipe is full are dropped. When this is
+    /// enabled, these signals are instead counted in a fixed-size table that is drained once the
+    /// pipe is empty. The signal handler does not allocate, so it remains async-signal-safe.
+    /// However, the relative order of the signals that did not fit into the pipe is lost.
+    ///
+    /// The pipe is full once it holds [`set_capacity`](Self::set_capacity) signals, or once the
+    /// operating system's buffer is exhausted, whichever comes first. On Windows, there is no
+    /// such buffer, so signals are only dropped if a capacity is set.
+    pub fn set_unbounded(&mut self, unbounded: bool) {
+        self.notifier.set_unbounded(unbounded);
+    }
+
+    /// Set what happens to signals that arrive while the internal pipe is full.
+    ///
+    /// Dropped signals are reported to the [diagnostic hook](Self::set_diagnostic_hook), whether
+    /// they were the newest or the oldest one. Returns an error for
+    /// [`QueueFullPolicy::Block`], which can't be implemented in a signal handler.
+    pub fn set_queue_full_policy(&mut self, policy: QueueFullPolicy) -> io::Result<()> {
+        match policy {
+            QueueFullPolicy::DropNewest => self.notifier.set_drop_oldest(false),
+            QueueFullPolicy::DropOldest => self.notifier.set_drop_oldest(true),
+            QueueFullPolicy::Block => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "signal handlers can't block until the pipe has room",
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the number of signals that were dropped because the internal pipe was full.
+    ///
+    /// This counts every dropped signal since the `Signals` was created, whether or not a
+    /// [diagnostic hook](Self::set_diagnostic_hook) is set.
+    pub fn dropped_count(&self) -> usize {
+        self.report_dropped();
+        self.dropped.load(Ordering::SeqCst)
+    }
+
+    /// Set the maximum number of signals that can be waiting in the internal pipe.
+    ///
+    /// Signals that arrive once this many are waiting are dropped, unless
+    /// [`set_unbounded`](Self::set_unbounded) is enabled. `None`, the default, only limits the
+    /// pipe by the operating system's buffer.
+    pub fn set_capacity(&mut self, capacity: Option<usize>) {
+        self.notifier.set_capacity(capacity);
+    }
+
+    /// Get the maximum number of signals that can be waiting in the internal pipe.
+    ///
+    /// This is the value set through [`set_capacity`](Self::set_capacity), or `None` if the pipe
+    /// is only limited by the operating system's buffer.
+    pub fn capacity(&self) -> Option<usize> {
+        self.notifier.capacity()
+    }
+
+    /// Set whether signals that are already waiting to be received are merged with new ones.
+    ///
+    /// When enabled, a signal that arrives while the same signal is still waiting in the internal
+    /// pipe is not queued again. This mirrors how the operating system merges standard signals,
+    /// and keeps a burst of the same signal from filling up the pipe.
+    pub fn set_coalescing(&mut self, coalesce: bool) {
+        self.notifier.set_coalesce(coalesce);
+    }
+
+    /// Try to receive the next signal without waiting.
+    ///
+    /// Returns `Ok(None)` if no signal is currently available. This is useful when polling the
+    /// underlying file descriptor through an external event loop.
+    ///
+    /// Such an event loop should call this until it returns `Ok(None)` each time the file
+    /// descriptor becomes readable. Signals that were moved out of the pipe, e.g. into the
+    /// overflow used by [`set_unbounded`](Self::set_unbounded), don't make the file descriptor
+    /// readable again, so an edge-triggered poller would not report them otherwise.
+    pub fn try_next_signal(&self) -> io::Result<Option<Signal>> {
+        self.try_signal()
+    }
+
+    /// Stop discarding deliveries of a signal that was [disarmed](Signals::disarm).
+    ///
+    /// Signals are armed when they are added.
+    pub fn arm(&mut self, signal: Signal) {
+        self.disarmed.remove(signal);
+    }
+
+    /// Discard deliveries of a signal, while keeping it registered.
+    ///
+    /// This is cheaper than removing and adding the signal again if it is toggled frequently.
+    /// Since the signal stays registered, its default action is still suppressed while it is
+    /// disarmed.
+    pub fn disarm(&mut self, signal: Signal) {
+        self.disarmed.insert(signal);
+    }
+
+    /// Register a waker to be woken once a signal may be available.
+    ///
+    /// Together with [`Signals::try_next_signal`], this can be used to build a future without
+    /// going through the [`Stream`] implementation. The contract is the same as for
+    /// [`Future::poll`]:
+    ///
+    /// - The waker is only woken once; it must be registered again after each wakeup.
+    /// - Register the waker first and then call [`Signals::try_next_signal`], or call it again
+    ///   after registering, so that a signal that arrives in between isn't missed.
+    /// - Wakeups may be spurious, in which case no signal is available yet.
+    ///
+    /// If a signal may already be available, the waker is woken immediately.
+    pub fn register_readiness(&self, waker: &Waker) {
+        let queued = !self
+            .queue
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_empty();
+
+        if queued {
+            waker.wake_by_ref();
+        } else {
+            self.notifier.register(waker);
+        }
+    }
+
+    /// Get the [`Async`] wrapper around the read end of the internal pipe.
+    ///
+    /// This gives access to the readiness API of [`async-io`], e.g. to wait for readability or to
+    /// inspect the socket. Prefer [`Signals::readable`] where it is enough.
+    ///
+    /// Don't read from the pipe, neither through the `Async` wrapper nor through the socket
+    /// itself. Signals are written into it in a format that is private to this crate, and
+    /// whatever is read is lost to the `Signals`; a partial read leaves the pipe in a state
+    /// that makes every later read fail. Also note that readiness of the pipe is not the whole
+    /// story: signals that didn't fit into the pipe in [unbounded](Self::set_unbounded) mode,
+    /// or that were moved into the internal queue, are available without the pipe being readable.
+    ///
+    /// [`Async`]: https://docs.rs/async-io/latest/async_io/struct.Async.html
+    /// [`async-io`]: https://docs.rs/async-io
+    #[cfg(unix)]
+    pub fn as_async_ref(&self) -> &async_io::Async<std::os::unix::net::UnixStream> {
+        self.notifier.as_async()
+    }
+
+    /// Wait until a signal may be available, without taking it.
+    ///
+    /// This is the equivalent of [`Async::readable`] for `Signals`, and is useful to compose
+    /// `Signals` with other event sources. Once this resolves, the signal can be taken through
+    /// [`Signals::try_next_signal`] or the [`Stream`] implementation.
+    ///
+    /// Like [`Async::readable`], this may resolve spuriously, e.g. for a signal that has been
+    /// removed or disarmed since it was received.
+    ///
+    /// [`Async::readable`]: https://docs.rs/async-io/latest/async_io/struct.Async.html#method.readable
+    pub async fn readable(&self) -> io::Result<()> {
+        Readable(self).await
+    }
+
+    /// Set the maximum number of signals that are taken at once.
+    ///
+    /// This limits the batches returned by [`Signals::recv_all`] and
+    /// [`Signals::poll_next_batch`], which trades throughput for latency: with a batch size of
+    /// one, other work can run between every signal. At least one signal is always taken. By
+    /// default, there is no limit.
+    pub fn set_batch_size(&mut self, size: Option<usize>) {
+        self.batch_size = size.map_or(usize::MAX, |size| size.max(1));
+    }
+
+    /// Enable or disable counting how many times each signal is received.
+    ///
+    /// When enabled, every signal returned by this `Signals` is counted. The counts can be
+    /// retrieved using [`counts`](Self::counts). Disabling counting resets the counts.
+    pub fn set_counting(&mut self, counting: bool) {
+        if !counting {
+            self.counts = None;
+        } else if self.counts.is_none() {
+            self.counts = Some(Mutex::new(HashMap::new()));
+        }
+    }
+
+    /// Get the number of times each signal was received since counting was enabled.
+    ///
+    /// Signals are yielded in ascending order. If counting is not enabled, this is empty.
+    pub fn counts(&self) -> impl Iterator<Item = (Signal, u64)> {
+        let mut counts = match &self.counts {
+            Some(counts) => counts
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .map(|(&signal, &count)| (signal, count))
+                .collect::<Vec<_>>(),
+            None => Vec::new(),
+        };
+
+        counts.sort_unstable();
+        counts.into_iter()
+    }
+
+    /// Poll for the next signal.
+    pub(crate) fn poll_signal(&self, cx: &mut Context<'_>) -> Poll<io::Result<Signal>> {
+        self.report_dropped();
+
+        loop {
+            let signal = match self.next_queued() {
+                Ok(Some(signal)) => signal,
+                Ok(None) => ready!(self.notifier.poll_next(cx)).map_err(|e| self.report(e))?,
+                Err(e) => return Poll::Ready(Err(self.report(e))),
+            };
+
+            if self.accept(signal) {
+                return Poll::Ready(Ok(signal));
+            }
+        }
+    }
+
+    /// Get the next signal without waiting.
+    fn try_signal(&self) -> io::Result<Option<Signal>> {
+        self.report_dropped();
+
+        loop {
+            let signal = match self.next_queued().map_err(|e| self.report(e))? {
+                Some(signal) => signal,
+                None => match self.notifier.try_next().map_err(|e| self.report(e))? {
+                    Some(signal) => signal,
+                    None => return Ok(None),
+                },
+            };
+
+            if self.accept(signal) {
+                return Ok(Some(signal));
+            }
+        }
+    }
+
+    /// Report the signals that were dropped to the diagnostic hook.
+    fn report_dropped(&self) {
+        for (signal, count) in self.notifier.take_dropped() {
+            self.dropped.fetch_add(count, Ordering::SeqCst);
+
+            if let Some(hook) = &self.hook {
+                if self.signal_ids.contains_key(&signal) {
+                    for _ in 0..count {
+                        hook(self.name(), Diagnostic::Dropped(signal));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Report a handler for the signal that wasn't installed by this crate.
+    ///
+    /// This takes a lock and queries the current handler, so it only runs in debug builds.
+    fn check_conflict(&self, signal: Signal) {
+        #[cfg(all(unix, debug_assertions))]
+        if let Some(hook) = &self.hook {
+            if foreign_handler(signal) {
+                hook(self.name(), Diagnostic::ConflictingHandler(signal));
+            }
+        }
+
+        #[cfg(not(all(unix, debug_assertions)))]
+        let _ = signal;
+    }
+
+    /// Report an error to the diagnostic hook.
+    fn report(&self, error: io::Error) -> io::Error {
+        if let Some(hook) = &self.hook {
+            hook(self.name(), Diagnostic::ReadError(error.kind()));
+        }
+
+        error
+    }
+
+    /// Take the next signal out of the internal queue.
+    fn next_queued(&self) -> io::Result<Option<Signal>> {
+        let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+
+        if self.priority.is_empty() && self.round_robin.is_none() {
+            return Ok(queue.pop_front());
+        }
+
+        // Move every available signal into the queue, so that we can pick the one that has the
+        // highest priority, or whose turn it is.
+        while let Some(signal) = self.notifier.try_next()? {
+            queue.push_back(signal);
+        }
+
+        if let (true, Some(last)) = (self.priority.is_empty(), &self.round_robin) {
+            let mut last = last.lock().unwrap_or_else(|e| e.into_inner());
+
+            // Pick the oldest occurrence of the signal that comes next after the last one, in
+            // order of signal numbers.
+            let last_number = last.map_or(0, |signal| signal.number());
+            let turn = |signal: &Signal| (signal.number() - last_number - 1).rem_euclid(64);
+            let index = queue
+                .iter()
+                .enumerate()
+                .min_by_key(|(index, signal)| (turn(signal), *index))
+                .map(|(index, _)| index);
+
+            let signal = index.and_then(|index| queue.remove(index));
+            if signal.is_some() {
+                *last = signal;
+            }
+            return Ok(signal);
+        }
+
+        let rank = |signal: &Signal| {
+            self.priority
+                .iter()
+                .position(|s| s == signal)
+                .unwrap_or(self.priority.len())
+        };
+        let index = queue
+            .iter()
+            .enumerate()
+            .min_by_key(|(index, signal)| (rank(signal), *index))
+            .map(|(index, _)| index);
+
+        Ok(index.and_then(|index| queue.remove(index)))
+    }
+
+    /// Tell whether a received signal should be returned, and record it if so.
+    fn accept(&self, signal: Signal) -> bool {
+        // Skip signals that were received before they were removed.
+        if !self.signal_ids.contains_key(&signal) {
+            return false;
+        }
+
+        // Skip signals that are disarmed.
+        if self.disarmed.contains(signal) {
+            return false;
+        }
+
+        self.record(signal);
+        true
+    }
+
+    /// Record that a signal was received.
+    fn record(&self, signal: Signal) {
+        if let Some(counts) = &self.counts {
+            *counts
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .entry(signal)
+                .or_insert(0) += 1;
+        }
+    }
+}
+
+/// The signals for which `signal-hook-registry` installed its handler through this crate.
+///
+/// The handler is never uninstalled, so this only grows.
+#[cfg(all(unix, debug_assertions))]
+static INSTALLED: Mutex<SignalSet> = Mutex::new(SignalSet::new());
+
+/// Remember that `signal-hook-registry` installed its handler for a signal.
+fn mark_installed(signal: Signal) {
+    #[cfg(all(unix, debug_assertions))]
+    INSTALLED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(signal);
+
+    #[cfg(not(all(unix, debug_assertions)))]
+    let _ = signal;
+}
+
+/// Tell whether a signal has a handler that wasn't installed through this crate.
+#[cfg(all(unix, debug_assertions))]
+fn foreign_handler(signal: Signal) -> bool {
+    if INSTALLED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .contains(signal)
+    {
+        return false;
+    }
+
+    let mut action = std::mem::MaybeUninit::<libc::sigaction>::uninit();
+
+    // SAFETY: A null action only queries the current one.
+    let action = unsafe {
+        if libc::sigaction(signal.number(), std::ptr::null(), action.as_mut_ptr()) != 0 {
+            return false;
+        }
+        action.assume_init()
+    };
+
+    action.sa_sigaction != libc::SIG_DFL && action.sa_sigaction != libc::SIG_IGN
+}
+
+/// Send a signal to a process.
+///
+/// This is a typed wrapper around `kill(2)`, and `pid` is passed to it unchanged. In particular,
+/// `0` sends the signal to every process in the caller's process group, and `-1` sends it to
+/// every process that the caller has permission to signal. To signal another process group, use
+/// [`send_to_group`].
+#[cfg(unix)]
+pub fn send(pid: i32, signal: Signal) -> io::Result<()> {
+    // SAFETY: kill() has no memory safety requirements.
+    if unsafe { libc::kill(pid, signal.number()) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+/// Send a signal to every process in a process group.
+///
+/// This is a typed wrapper around `killpg(3)`.
+#[cfg(unix)]
+pub fn send_to_group(pgid: i32, signal: Signal) -> io::Result<()> {
+    // SAFETY: killpg() has no memory safety requirements.
+    if unsafe { libc::killpg(pgid, signal.number()) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+/// Make sure that a signal can be registered.
+fn check_forbidden(signal: Signal) -> io::Result<()> {
+    // `signal-hook-registry` panics on signals that can't be handled, rather than failing.
+    #[cfg(unix)]
+    if registry::FORBIDDEN.contains(&signal.number()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} can't be handled", signal.name()),
+        ));
+    }
+
+    #[cfg(not(unix))]
+    let _ = signal;
+
+    Ok(())
+}
+
+/// Wait for a Ctrl-C.
+///
+/// This listens for `SIGINT` on Unix and for `CTRL_C_EVENT` on Windows. The handler is registered
+/// when the future is first polled and unregistered once it completes or is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # futures_lite::future::block_on(async {
+/// async_signal::ctrl_c().await?;
+/// println!("Received Ctrl-C");
+/// # std::io::Result::Ok(())
+/// # });
+/// ```
+pub async fn ctrl_c() -> io::Result<()> {
+    let signals = Signals::new(Some(Signal::Int))?;
+    NextSignal(&signals).await?;
+    Ok(())
+}
+
+/// Wait for the first of a set of signals, and return the one that arrived.
+///
+/// This is a shortcut for creating a temporary [`Signals`] and waiting for its first signal. Like
+/// for [`ctrl_c`], the handlers are registered when the future is first polled and unregistered
+/// once it completes or is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_signal::Signal;
+///
+/// # futures_lite::future::block_on(async {
+/// let signal = async_signal::any_of(&[Signal::Term, Signal::Int]).await?;
+/// println!("Received {}", signal.name());
+/// # std::io::Result::Ok(())
+/// # });
+/// ```
+pub async fn any_of(signals: &[Signal]) -> io::Result<Signal> {
+    let signals = Signals::new(signals)?;
+    NextSignal(&signals).await
+}
+
+#[cfg(unix)]
+impl AsRawFd for Signals {
+    fn as_raw_fd(&self) -> RawFd {
+        self.notifier.as_raw_fd()
+    }
+}
+
+#[cfg(unix)]
+impl AsFd for Signals {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.notifier.as_fd()
+    }
+}
+
+/// On Windows, the handle is an event object that is signaled while signals are available to be
+/// received, so it can be waited on with e.g. `WaitForSingleObject`.
+#[cfg(windows)]
+impl AsRawHandle for Signals {
+    fn as_raw_handle(&self) -> RawHandle {
+        self.notifier.as_raw_handle()
+    }
+}
+
+/// On Windows, the handle is an event object that is signaled while signals are available to be
+/// received, so it can be waited on with e.g. `WaitForSingleObject`.
+#[cfg(windows)]
+impl AsHandle for Signals {
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        self.notifier.as_handle()
+    }
+}
+
+/// Readiness is edge-triggered, like for any other `mio` source.
+///
+/// After a readable event, receive signals with [`Signals::try_next_signal`] until it returns
+/// `Ok(None)`. Signals that are kept in memory rather than in the pipe, such as those that
+/// didn't fit into it under [`Signals::set_unbounded`], don't make the file descriptor readable,
+/// so stopping early may leave them waiting until another signal arrives.
+#[cfg(all(unix, feature = "mio"))]
+impl mio::event::Source for Signals {
+    fn register(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        mio::unix::SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        mio::unix::SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &mio::Registry) -> io::Result<()> {
+        mio::unix::SourceFd(&self.as_raw_fd()).deregister(registry)
+    }
+}
+
+/// A future that waits for the next signal.
+struct NextSignal<'a>(&'a Signals);
+
+impl Future for NextSignal<'_> {
+    type Output = io::Result<Signal>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_signal(cx)
+    }
+}
+
+/// A future that waits until a signal may be available.
+struct Readable<'a>(&'a Signals);
+
+impl Future for Readable<'_> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let queued = !self
+            .0
+            .queue
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_empty();
+
+        if queued {
+            Poll::Ready(Ok(()))
+        } else {
+            self.0.notifier.poll_ready(cx)
+        }
+    }
+}
+
+/// Handler IDs shared between [`Signals::add_signals_async`] and the thread pool.
+#[cfg(feature = "blocking")]
+type PendingIds = Arc<Mutex<Option<Vec<(Signal, SigId)>>>>;
+
+/// Handlers registered by [`Signals::add_signals_async`] that haven't been handed over yet.
+///
+/// Dropping this unregisters the handlers and takes the signals out of the notifier again.
+#[cfg(feature = "blocking")]
+struct PendingRegistration<'a> {
+    owner: &'a mut Signals,
+
+    /// The signals that were added to the notifier.
+    signals: Vec<Signal>,
+
+    /// The handlers registered so far, or `None` once no more may be registered.
+    ids: PendingIds,
+}
+
+#[cfg(feature = "blocking")]
+impl PendingRegistration<'_> {
+    /// Hand the registered handlers over to the `Signals`.
+    fn finish(mut self) {
+        let ids = self
+            .ids
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .unwrap_or_default();
+
+        for (signal, _) in &ids {
+            mark_installed(*signal);
+        }
+        self.owner.signal_ids.extend(ids);
+        self.signals.clear();
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Drop for PendingRegistration<'_> {
+    fn drop(&mut self) {
+        // Taking the IDs keeps the thread pool from registering any more handlers.
+        let ids = self
+            .ids
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .unwrap_or_default();
+
+        for (_, id) in ids {
+            registry::unregister(id);
+        }
+        for signal in self.signals.drain(..) {
+            let _ = self.owner.notifier.remove_signal(signal);
+        }
+    }
+}
+
+impl Unpin for Signals {}
+
+impl Stream for Signals {
+    type Item = io::Result<Signal>;
 
     #[inline]
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
@@ -406,11 +2255,19 @@ impl Stream for Signals {
     }
 }
 
+impl FusedStream for Signals {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        // This stream is expected to never end.
+        false
+    }
+}
+
 impl Stream for &Signals {
     type Item = io::Result<Signal>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let signal = ready!(self.notifier.poll_next(cx))?;
+        let signal = ready!(self.poll_signal(cx))?;
         Poll::Ready(Some(Ok(signal)))
     }
 
@@ -420,3 +2277,246 @@ impl Stream for &Signals {
         (usize::MAX, None)
     }
 }
+
+impl FusedStream for &Signals {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        // This stream is expected to never end.
+        false
+    }
+}
+
+/// A stream of signals along with the time at which they were received.
+///
+/// This is created by [`Signals::timestamped`].
+#[derive(Debug)]
+pub struct Timestamped<'a> {
+    /// The signals to receive.
+    signals: &'a Signals,
+}
+
+impl Stream for Timestamped<'_> {
+    type Item = io::Result<(Signal, Instant)>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let signal = ready!(self.signals.poll_signal(cx))?;
+        Poll::Ready(Some(Ok((signal, Instant::now()))))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // This stream is expected to never end.
+        (usize::MAX, None)
+    }
+}
+
+impl FusedStream for Timestamped<'_> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        // This stream is expected to never end.
+        false
+    }
+}
+
+/// A stream that emits a signal once a burst of it has settled.
+///
+/// This is created by [`Signals::debounced`].
+#[cfg(unix)]
+#[derive(Debug)]
+pub struct Debounced<'a> {
+    /// The signals to receive.
+    signals: &'a Signals,
+
+    /// The signal to debounce.
+    signal: Signal,
+
+    /// How long the signal must not arrive before it is emitted.
+    window: std::time::Duration,
+
+    /// The timer for the end of the current window, if the signal has arrived.
+    timer: Option<async_io::Timer>,
+}
+
+#[cfg(unix)]
+impl Stream for Debounced<'_> {
+    type Item = io::Result<Signal>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        // Take every available signal, restarting the window for each occurrence.
+        while let Poll::Ready(res) = this.signals.poll_signal(cx) {
+            if res? == this.signal {
+                match &mut this.timer {
+                    Some(timer) => timer.set_after(this.window),
+                    None => this.timer = Some(async_io::Timer::after(this.window)),
+                }
+            }
+        }
+
+        match &mut this.timer {
+            Some(timer) => {
+                ready!(Pin::new(timer).poll(cx));
+                this.timer = None;
+                Poll::Ready(Some(Ok(this.signal)))
+            }
+            None => Poll::Pending,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // This stream is expected to never end.
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(unix)]
+impl FusedStream for Debounced<'_> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        // This stream is expected to never end.
+        false
+    }
+}
+
+/// A stream of signals along with the number of occurrences that each one stands for.
+///
+/// This is created by [`Signals::occurrences`].
+#[derive(Debug)]
+pub struct Occurrences<'a> {
+    /// The signals to receive.
+    signals: &'a Signals,
+}
+
+impl Stream for Occurrences<'_> {
+    type Item = io::Result<(Signal, usize)>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let signal = ready!(self.signals.poll_signal(cx))?;
+        let merged = self.signals.notifier.take_merged(signal);
+        Poll::Ready(Some(Ok((signal, merged + 1))))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // This stream is expected to never end.
+        (usize::MAX, None)
+    }
+}
+
+impl FusedStream for Occurrences<'_> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        // This stream is expected to never end.
+        false
+    }
+}
+
+/// A sink that removes the signals sent into it from a [`Signals`].
+///
+/// This is created by [`Signals::remover`].
+#[cfg(feature = "futures-sink")]
+#[derive(Debug)]
+pub struct Remover<'a> {
+    /// The signals to remove from.
+    signals: &'a mut Signals,
+}
+
+#[cfg(feature = "futures-sink")]
+impl futures_sink::Sink<Signal> for Remover<'_> {
+    type Error = io::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, signal: Signal) -> io::Result<()> {
+        self.signals.remove_signals(Some(signal))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// A handle that injects signals into a [`Signals`].
+///
+/// This is created by [`Signals::control_handle`].
+#[derive(Clone)]
+pub struct SignalsControl {
+    /// The state shared with the `Signals`.
+    control: Arc<Control>,
+}
+
+impl fmt::Debug for SignalsControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignalsControl").finish_non_exhaustive()
+    }
+}
+
+impl SignalsControl {
+    /// Deliver a signal as if it had been received, without sending an actual signal.
+    ///
+    /// This works like [`Signals::inject`]. If the `Signals` has been dropped, this does nothing.
+    pub fn inject(&self, signal: Signal) {
+        if let Some(inject) = &*self
+            .control
+            .inject
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+        {
+            inject(signal);
+        }
+    }
+}
+
+/// The state shared between a [`Signals`] and its control handles.
+struct Control {
+    /// Injects a signal into the notifier, or `None` once the `Signals` has been dropped.
+    inject: Mutex<Option<Box<Injector>>>,
+}
+
+/// A function that injects a signal into a notifier.
+type Injector = dyn Fn(Signal) + Send + Sync + 'static;
+
+/// A stream that observes the signals received by a [`Signals`].
+///
+/// This is created by [`Signals::observe`].
+#[derive(Debug)]
+pub struct Observer {
+    /// The signals to receive.
+    signals: Signals,
+
+    /// Whether an error ended the stream.
+    terminated: bool,
+}
+
+impl Stream for Observer {
+    type Item = Signal;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        match ready!(self.signals.poll_signal(cx)) {
+            Ok(signal) => Poll::Ready(Some(signal)),
+            Err(_) => {
+                self.terminated = true;
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+impl FusedStream for Observer {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
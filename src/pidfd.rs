@@ -0,0 +1,50 @@
+//! Waiting for a child process to exit using a pidfd.
+
+use async_io::Async;
+use rustix::process::{pidfd_open, Pid, PidfdFlags};
+
+use std::io;
+use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, OwnedFd, RawFd};
+
+/// Wait for a specific child process to exit.
+///
+/// This uses a pidfd, which becomes readable once the process has exited. Unlike listening for
+/// `SIGCHLD`, this only concerns a single process and doesn't involve any process-wide state.
+/// It requires Linux 5.3 or later.
+///
+/// Note that this does not reap the child process. It still needs to be waited on afterwards,
+/// e.g. through [`std::process::Child::wait`], which will then return immediately.
+#[derive(Debug)]
+pub struct ChildSignal {
+    /// The pidfd referring to the child process.
+    fd: Async<OwnedFd>,
+}
+
+impl ChildSignal {
+    /// Create a new `ChildSignal` for the process with the given PID.
+    pub fn new(pid: i32) -> io::Result<Self> {
+        let pid = Pid::from_raw(pid).ok_or_else(|| io::Error::from(io::ErrorKind::InvalidInput))?;
+        let fd = pidfd_open(pid, PidfdFlags::empty())?;
+
+        Ok(Self {
+            fd: Async::new(fd)?,
+        })
+    }
+
+    /// Wait for the process to exit.
+    pub async fn wait(&self) -> io::Result<()> {
+        self.fd.readable().await
+    }
+}
+
+impl AsRawFd for ChildSignal {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+
+impl AsFd for ChildSignal {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
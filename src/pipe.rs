@@ -3,7 +3,6 @@
 use crate::Signal;
 
 use async_io::Async;
-use futures_core::ready;
 use futures_io::AsyncRead;
 
 use std::io::{self, prelude::*};
@@ -11,10 +10,17 @@ use std::mem;
 use std::os::unix::io::{AsFd, AsRawFd, BorrowedFd, RawFd};
 use std::os::unix::net::UnixStream;
 use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
 
 const BUFFER_LEN: usize = mem::size_of::<std::os::raw::c_int>();
 
+/// The number of slots in the per-signal tables.
+///
+/// All of the signals that we support have a number below this.
+const TABLE_LEN: usize = 32;
+
 /// The notifier that uses an asynchronous pipe.
 #[derive(Debug)]
 pub(super) struct Notifier {
@@ -23,16 +29,47 @@ pub(super) struct Notifier {
 
     /// The write end of the signal pipe.
     write: UnixStream,
+
+    /// State shared with the signal handlers.
+    state: Arc<State>,
+
+    /// Whether the overflow should be checked before the pipe on the next read.
+    ///
+    /// Under a sustained flood of signals the pipe may never run empty, so we alternate between
+    /// the pipe and the overflow to keep either of them from starving the other.
+    overflow_turn: AtomicBool,
 }
 
 impl Notifier {
     /// Create a new signal notifier.
     pub(super) fn new() -> io::Result<Self> {
+        // Running out of file descriptors is reported as is, so that the caller can tell from
+        // `raw_os_error()` why this failed and try again after freeing some up.
         let (read, write) = UnixStream::pair()?;
         let read = Async::new(read)?;
+        let read_fd = read.as_raw_fd();
         write.set_nonblocking(true)?;
 
-        Ok(Self { read, write })
+        Ok(Self {
+            read,
+            write,
+            state: Arc::new(State {
+                unbounded: AtomicBool::new(false),
+                coalesce: AtomicBool::new(false),
+                capacity: AtomicUsize::new(usize::MAX),
+                len: AtomicUsize::new(0),
+                pending: Default::default(),
+                merged: Default::default(),
+                overflow: Default::default(),
+                dropped: Default::default(),
+                any_dropped: AtomicBool::new(false),
+                paused: AtomicBool::new(false),
+                held: Default::default(),
+                drop_oldest: AtomicBool::new(false),
+                read_fd,
+            }),
+            overflow_turn: AtomicBool::new(false),
+        })
     }
 
     /// Add a signal to the notifier.
@@ -44,21 +81,142 @@ impl Notifier {
     ) -> io::Result<impl Fn() + Send + Sync + 'static> {
         let number = signal.number();
         let write = self.write.try_clone()?;
+        let state = self.state.clone();
 
         Ok(move || {
-            // SAFETY: to_ne_bytes() and write() are both signal safe.
-            let bytes = number.to_ne_bytes();
-            let _ = (&write).write(&bytes);
+            // SAFETY: This only uses atomics and write(), which are both signal safe.
+            state.push(&write, number);
+        })
+    }
+
+    /// Set whether signals that do not fit into the pipe should be kept.
+    pub(super) fn set_unbounded(&mut self, unbounded: bool) {
+        self.state.unbounded.store(unbounded, Ordering::SeqCst);
+    }
+
+    /// Set whether a signal that is already pending should be merged with new occurrences.
+    pub(super) fn set_coalesce(&mut self, coalesce: bool) {
+        self.state.coalesce.store(coalesce, Ordering::SeqCst);
+    }
+
+    /// Set whether the oldest signal in the pipe is dropped to make room for a new one.
+    pub(super) fn set_drop_oldest(&mut self, drop_oldest: bool) {
+        self.state.drop_oldest.store(drop_oldest, Ordering::SeqCst);
+    }
+
+    /// Set the maximum number of signals that can be in the pipe at once.
+    pub(super) fn set_capacity(&mut self, capacity: Option<usize>) {
+        self.state
+            .capacity
+            .store(capacity.unwrap_or(usize::MAX), Ordering::SeqCst);
+    }
+
+    /// Take the number of occurrences of a signal that were merged into a pending one.
+    pub(super) fn take_merged(&self, signal: Signal) -> usize {
+        self.state.merged[signal.number() as usize].swap(0, Ordering::SeqCst)
+    }
+
+    /// Get the maximum number of signals that can be queued at once.
+    pub(super) fn capacity(&self) -> Option<usize> {
+        match self.state.capacity.load(Ordering::SeqCst) {
+            usize::MAX => None,
+            capacity => Some(capacity),
+        }
+    }
+
+    /// Take the number of signals that were dropped since the last call, for each signal.
+    pub(super) fn take_dropped(&self) -> impl Iterator<Item = (Signal, usize)> + '_ {
+        // Clear the flag before taking the counts, so that a drop in between isn't missed.
+        let any = self.state.any_dropped.load(Ordering::SeqCst)
+            && self.state.any_dropped.swap(false, Ordering::SeqCst);
+
+        self.state
+            .dropped
+            .iter()
+            .take(if any { TABLE_LEN } else { 0 })
+            .enumerate()
+            .filter_map(|(number, count)| match count.swap(0, Ordering::SeqCst) {
+                0 => None,
+                count => Signal::from_number(number as _).map(|signal| (signal, count)),
+            })
+    }
+
+    /// Deliver a signal as if it had been received.
+    pub(super) fn inject(&self, signal: Signal) {
+        self.state.push(&self.write, signal.number());
+    }
+
+    /// Get a closure that delivers signals as if they had been received.
+    ///
+    /// The closure must not be called after the notifier is dropped.
+    pub(super) fn injector(&self) -> io::Result<impl Fn(Signal) + Send + Sync + 'static> {
+        let write = self.write.try_clone()?;
+        let state = self.state.clone();
+
+        Ok(move |signal: Signal| {
+            state.push(&write, signal.number());
         })
     }
 
+    /// Hold back new signals until `resume` is called.
+    pub(super) fn pause(&self) {
+        self.state.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Stop holding back signals, and deliver the ones that were held back.
+    pub(super) fn resume(&self) {
+        self.state.paused.store(false, Ordering::SeqCst);
+
+        for (number, held) in self.state.held.iter().enumerate() {
+            if held.swap(false, Ordering::SeqCst) {
+                self.state.push(&self.write, number as _);
+            }
+        }
+    }
+
     /// Remove a signal from the notifier.
     pub(super) fn remove_signal(&mut self, _signal: Signal) -> io::Result<()> {
         Ok(())
     }
 
+    /// Register a waker to be woken once a signal may be available.
+    pub(super) fn register(&self, waker: &Waker) {
+        // Signals in the overflow don't make the pipe readable.
+        if self.state.has_overflow() {
+            waker.wake_by_ref();
+            return;
+        }
+
+        if self
+            .read
+            .poll_readable(&mut Context::from_waker(waker))
+            .is_ready()
+        {
+            waker.wake_by_ref();
+        }
+    }
+
+    /// Get the read end of the signal pipe.
+    pub(super) fn as_async(&self) -> &Async<UnixStream> {
+        &self.read
+    }
+
+    /// Wait until a signal may be available, without taking it.
+    pub(super) fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // Signals in the overflow don't make the pipe readable.
+        if self.state.has_overflow() {
+            return Poll::Ready(Ok(()));
+        }
+
+        self.read.poll_readable(cx)
+    }
+
     /// Get the next signal.
     pub(super) fn poll_next(&self, cx: &mut Context<'_>) -> Poll<io::Result<Signal>> {
+        if let Some(signal) = self.overflow_first() {
+            return Poll::Ready(Ok(signal));
+        }
+
         let mut buffer = [0; BUFFER_LEN];
         let mut buffer_len = 0;
 
@@ -70,7 +228,21 @@ impl Notifier {
 
             // Try to fill up the entire buffer.
             let buf_range = buffer_len..BUFFER_LEN;
-            let res = ready!(Pin::new(&mut &self.read).poll_read(cx, &mut buffer[buf_range]));
+            let res = match Pin::new(&mut &self.read).poll_read(cx, &mut buffer[buf_range]) {
+                // The pipe is empty, fall back to the signals that didn't fit into it.
+                Poll::Pending if buffer_len == 0 => match self.state.pop_overflow() {
+                    Some(signal) => return Poll::Ready(Ok(signal)),
+                    None => return Poll::Pending,
+                },
+
+                // The signal number is always written all at once, so the rest of it should
+                // already be in the pipe. Returning `Pending` here would lose the bytes that
+                // were already read, so report the error instead.
+                Poll::Pending => {
+                    return Poll::Ready(Err(io::Error::from(io::ErrorKind::InvalidData)))
+                }
+                Poll::Ready(res) => res,
+            };
 
             match res {
                 Ok(0) => return Poll::Ready(Err(io::Error::from(io::ErrorKind::UnexpectedEof))),
@@ -79,17 +251,236 @@ impl Notifier {
             }
         }
 
+        self.overflow_turn.store(true, Ordering::SeqCst);
+        Poll::Ready(self.state.pop_pipe(buffer))
+    }
+
+    /// Get the next signal without waiting.
+    pub(super) fn try_next(&self) -> io::Result<Option<Signal>> {
+        if let Some(signal) = self.overflow_first() {
+            return Ok(Some(signal));
+        }
+
+        let mut buffer = [0; BUFFER_LEN];
+        let mut buffer_len = 0;
+
+        // Read into the buffer.
+        while buffer_len < BUFFER_LEN {
+            match self.read.get_ref().read(&mut buffer[buffer_len..]) {
+                Ok(0) => return Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+                Ok(n) => buffer_len += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock && buffer_len == 0 => {
+                    return Ok(self.state.pop_overflow())
+                }
+
+                // The signal number is always written all at once, so a partial read means that
+                // something went wrong.
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    return Err(io::Error::from(io::ErrorKind::InvalidData))
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.overflow_turn.store(true, Ordering::SeqCst);
+        self.state.pop_pipe(buffer).map(Some)
+    }
+
+    /// Take a signal from the overflow if it is its turn.
+    fn overflow_first(&self) -> Option<Signal> {
+        if self.overflow_turn.swap(false, Ordering::SeqCst) {
+            self.state.pop_overflow()
+        } else {
+            None
+        }
+    }
+}
+
+/// State shared between the notifier and the signal handlers.
+///
+/// Everything that the signal handlers touch is atomic, since they can't take locks or allocate.
+/// Per-signal tables are indexed by signal number.
+///
+/// The handlers may run on any thread while the notifier is reading on another one. This relies on
+/// two guarantees: `write()` is async-signal-safe and writes the four bytes of a signal number
+/// atomically (they are far below `PIPE_BUF`), and the atomics used here are lock-free, so a
+/// handler never waits on the reader or vice versa.
+#[derive(Debug)]
+struct State {
+    /// Whether signals that do not fit into the pipe should be kept in `overflow`.
+    unbounded: AtomicBool,
+
+    /// Whether a signal that is already pending should be merged with new occurrences.
+    coalesce: AtomicBool,
+
+    /// The maximum number of signals in the pipe.
+    capacity: AtomicUsize,
+
+    /// The number of signals in the pipe.
+    len: AtomicUsize,
+
+    /// Whether a signal is currently pending, used for coalescing.
+    pending: [AtomicBool; TABLE_LEN],
+
+    /// The number of occurrences that were merged into a pending signal and not taken yet.
+    merged: [AtomicUsize; TABLE_LEN],
+
+    /// The number of signals that did not fit into the pipe.
+    ///
+    /// Rather than queueing these signals, which would require allocating in the signal handler,
+    /// we only count them. This means that the order of these signals is lost.
+    overflow: [AtomicUsize; TABLE_LEN],
+
+    /// The number of dropped signals that have not been reported yet.
+    dropped: [AtomicUsize; TABLE_LEN],
+
+    /// Whether any of the counts in `dropped` may be nonzero, so that the reader doesn't have to
+    /// check all of them every time.
+    any_dropped: AtomicBool,
+
+    /// Whether new signals are being held back.
+    paused: AtomicBool,
+
+    /// Whether a signal was held back while paused.
+    ///
+    /// Like the kernel's set of pending signals, this only remembers that a signal arrived, not
+    /// how many times.
+    held: [AtomicBool; TABLE_LEN],
+
+    /// Whether the oldest signal in the pipe is dropped to make room for a new one.
+    drop_oldest: AtomicBool,
+
+    /// The read end of the pipe, used to drop the oldest signal.
+    ///
+    /// The handlers are unregistered before the notifier, and with it the pipe, is dropped, so
+    /// this stays valid for as long as the handlers can run.
+    read_fd: RawFd,
+}
+
+impl State {
+    /// Push a signal, from the signal handler.
+    fn push(&self, write: &UnixStream, number: std::os::raw::c_int) {
+        let index = number as usize;
+        if index >= TABLE_LEN {
+            return;
+        }
+
+        // Hold the signal back while paused. If we were resumed in the meantime, the held signals
+        // may have been delivered already, so make sure that this one isn't left behind.
+        if self.paused.load(Ordering::SeqCst) {
+            self.held[index].store(true, Ordering::SeqCst);
+            if self.paused.load(Ordering::SeqCst) || !self.held[index].swap(false, Ordering::SeqCst)
+            {
+                return;
+            }
+        }
+
+        // If the signal is already pending, merge this occurrence into it.
+        if self.coalesce.load(Ordering::SeqCst) && self.pending[index].swap(true, Ordering::SeqCst)
+        {
+            self.merged[index].fetch_add(1, Ordering::SeqCst);
+            return;
+        }
+
+        // Make sure that there is room for the signal, then write it into the pipe.
+        let fits = self.len.fetch_add(1, Ordering::SeqCst) < self.capacity.load(Ordering::SeqCst);
+        if fits && matches!((&*write).write(&number.to_ne_bytes()), Ok(BUFFER_LEN)) {
+            return;
+        }
+        self.len.fetch_sub(1, Ordering::SeqCst);
+
+        // The signal didn't fit.
+        if self.unbounded.load(Ordering::SeqCst) {
+            self.overflow[index].fetch_add(1, Ordering::SeqCst);
+        } else if self.drop_oldest.load(Ordering::SeqCst) && self.replace_oldest(write, number) {
+            // The oldest signal was dropped instead.
+        } else {
+            self.pending[index].store(false, Ordering::SeqCst);
+            self.record_dropped(index);
+        }
+    }
+
+    /// Count a dropped signal, from the signal handler.
+    fn record_dropped(&self, index: usize) {
+        self.dropped[index].fetch_add(1, Ordering::SeqCst);
+        self.any_dropped.store(true, Ordering::SeqCst);
+    }
+
+    /// Drop the oldest signal in the pipe and write this one in its place, from the signal
+    /// handler.
+    ///
+    /// Returns `false` if the pipe is empty, in which case nothing was dropped.
+    fn replace_oldest(&self, write: &UnixStream, number: std::os::raw::c_int) -> bool {
+        let mut buffer = [0; BUFFER_LEN];
+
+        // SAFETY: read() is signal safe, and `read_fd` is valid while handlers can run. Signal
+        // numbers are written all at once, so a full read takes exactly one of them.
+        let n = unsafe { libc::read(self.read_fd, buffer.as_mut_ptr().cast(), BUFFER_LEN) };
+        if n != BUFFER_LEN as isize {
+            return false;
+        }
+
+        // If the oldest signal is the same one, it stays pending.
+        let oldest = std::os::raw::c_int::from_ne_bytes(buffer) as usize;
+        if oldest < TABLE_LEN {
+            if oldest != number as usize {
+                self.pending[oldest].store(false, Ordering::SeqCst);
+            }
+            self.record_dropped(oldest);
+        }
+
+        // We just made room, so this fits, unless the reader took a signal in the meantime.
+        if !matches!((&*write).write(&number.to_ne_bytes()), Ok(BUFFER_LEN)) {
+            self.len.fetch_sub(1, Ordering::SeqCst);
+            self.pending[number as usize].store(false, Ordering::SeqCst);
+            self.record_dropped(number as usize);
+        }
+
+        true
+    }
+
+    /// Convert a buffer read from the pipe into a signal.
+    fn pop_pipe(&self, buffer: [u8; BUFFER_LEN]) -> io::Result<Signal> {
+        self.len.fetch_sub(1, Ordering::SeqCst);
+
         // Convert the buffer into a signal number.
         let number = std::os::raw::c_int::from_ne_bytes(buffer);
 
         // Convert the signal number into a signal.
         let signal = match Signal::from_number(number) {
             Some(signal) => signal,
-            None => return Poll::Ready(Err(io::Error::from(io::ErrorKind::InvalidData))),
+            None => return Err(io::Error::from(io::ErrorKind::InvalidData)),
         };
 
-        // Return the signal.
-        Poll::Ready(Ok(signal))
+        self.pending[number as usize].store(false, Ordering::SeqCst);
+        Ok(signal)
+    }
+
+    /// Tell whether any signals did not fit into the pipe.
+    fn has_overflow(&self) -> bool {
+        self.overflow
+            .iter()
+            .any(|count| count.load(Ordering::SeqCst) > 0)
+    }
+
+    /// Take one of the signals that did not fit into the pipe.
+    fn pop_overflow(&self) -> Option<Signal> {
+        self.overflow
+            .iter()
+            .enumerate()
+            .find_map(|(number, count)| {
+                count
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                        count.checked_sub(1)
+                    })
+                    .ok()
+                    .map(|_| number)
+            })
+            .and_then(|number| {
+                self.pending[number].store(false, Ordering::SeqCst);
+                Signal::from_number(number as _)
+            })
     }
 }
 
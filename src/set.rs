@@ -0,0 +1,187 @@
+//! A compact set of signals.
+
+use crate::Signal;
+
+use std::fmt;
+use std::iter::FromIterator;
+
+/// A set of signals.
+///
+/// Signal numbers are small, so this is backed by a bitset.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SignalSet {
+    /// The bits of the set, indexed by signal number.
+    bits: u64,
+}
+
+impl SignalSet {
+    /// Create a new, empty set of signals.
+    pub const fn new() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Add a signal to the set.
+    ///
+    /// Returns `true` if the signal was not already in the set.
+    pub fn insert(&mut self, signal: Signal) -> bool {
+        let present = self.contains(signal);
+        self.bits |= bit(signal);
+        !present
+    }
+
+    /// Remove a signal from the set.
+    ///
+    /// Returns `true` if the signal was in the set.
+    pub fn remove(&mut self, signal: Signal) -> bool {
+        let present = self.contains(signal);
+        self.bits &= !bit(signal);
+        present
+    }
+
+    /// Tell whether the set contains a signal.
+    pub fn contains(&self, signal: Signal) -> bool {
+        self.bits & bit(signal) != 0
+    }
+
+    /// Get the number of signals in the set.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Tell whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Get the signals that are in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Get the signals that are in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Get the signals that are in this set but not in the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// Iterate over the signals in the set, in order of signal number.
+    pub fn iter(&self) -> Iter {
+        Iter { bits: self.bits }
+    }
+}
+
+impl fmt::Debug for SignalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<Signal> for SignalSet {
+    fn from(signal: Signal) -> Self {
+        Self { bits: bit(signal) }
+    }
+}
+
+impl FromIterator<Signal> for SignalSet {
+    fn from_iter<I: IntoIterator<Item = Signal>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Signal> for SignalSet {
+    fn extend<I: IntoIterator<Item = Signal>>(&mut self, iter: I) {
+        for signal in iter {
+            self.insert(signal);
+        }
+    }
+}
+
+impl IntoIterator for SignalSet {
+    type Item = Signal;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &SignalSet {
+    type Item = Signal;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(unix)]
+impl From<&libc::sigset_t> for SignalSet {
+    fn from(sigset: &libc::sigset_t) -> Self {
+        Signal::iter_set(sigset).collect()
+    }
+}
+
+#[cfg(unix)]
+impl From<SignalSet> for libc::sigset_t {
+    fn from(set: SignalSet) -> Self {
+        let mut sigset = std::mem::MaybeUninit::<libc::sigset_t>::uninit();
+
+        unsafe {
+            // SAFETY: `sigemptyset` initializes the set.
+            libc::sigemptyset(sigset.as_mut_ptr());
+
+            for signal in set {
+                // SAFETY: The set is initialized and the signal number is valid.
+                libc::sigaddset(sigset.as_mut_ptr(), signal.number());
+            }
+
+            sigset.assume_init()
+        }
+    }
+}
+
+/// An iterator over the signals in a [`SignalSet`].
+#[derive(Debug, Clone)]
+pub struct Iter {
+    /// The bits that are yet to be yielded.
+    bits: u64,
+}
+
+impl Iterator for Iter {
+    type Item = Signal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.bits != 0 {
+            let number = self.bits.trailing_zeros();
+            self.bits &= self.bits - 1;
+
+            if let Some(signal) = Signal::from_number(number as _) {
+                return Some(signal);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+/// Get the bit corresponding to a signal.
+fn bit(signal: Signal) -> u64 {
+    1 << signal.number()
+}
@@ -0,0 +1,145 @@
+//! Waiting for signals synchronously using `sigwaitinfo`.
+
+use crate::{Signal, SignalSet};
+
+use std::borrow::Borrow;
+use std::convert::TryInto;
+use std::fmt;
+use std::io;
+use std::ptr;
+use std::time::{Duration, Instant};
+
+/// Wait for signals synchronously, without a reactor.
+///
+/// This uses `sigwaitinfo` and `sigtimedwait` to block the current thread until one of the
+/// signals arrives. Unlike [`Signals`](crate::Signals), no signal handlers are installed and no
+/// file descriptors are allocated.
+///
+/// # Blocking the signals
+///
+/// `sigwaitinfo` only picks up signals that are blocked. The signals must therefore be blocked in
+/// *every* thread of the process, e.g. by blocking them with `pthread_sigmask` in the main thread
+/// before any other threads are spawned, so that new threads inherit the mask. If a signal is not
+/// blocked in some thread, it may be delivered to that thread instead, where it will run the
+/// default action or whatever handler is installed.
+#[derive(Clone)]
+pub struct BlockingSignals {
+    /// The signals to wait for.
+    signals: SignalSet,
+
+    /// The signals to wait for, as a `sigset_t`.
+    sigset: libc::sigset_t,
+}
+
+impl fmt::Debug for BlockingSignals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockingSignals")
+            .field("signals", &self.signals)
+            .finish()
+    }
+}
+
+impl BlockingSignals {
+    /// Create a new `BlockingSignals` for the given signals.
+    ///
+    /// This does not block the signals; see the [type-level documentation](BlockingSignals).
+    pub fn new<B>(signals: impl IntoIterator<Item = B>) -> Self
+    where
+        B: Borrow<Signal>,
+    {
+        let signals: SignalSet = signals.into_iter().map(|signal| *signal.borrow()).collect();
+
+        Self {
+            signals,
+            sigset: signals.into(),
+        }
+    }
+
+    /// Get the signals that are waited for.
+    pub fn signals(&self) -> SignalSet {
+        self.signals
+    }
+
+    /// Block the current thread until one of the signals arrives.
+    pub fn wait(&self) -> io::Result<Signal> {
+        loop {
+            // SAFETY: `sigset` is a valid, initialized signal set.
+            let number = unsafe { libc::sigwaitinfo(&self.sigset, ptr::null_mut()) };
+
+            match self.check(number)? {
+                Some(signal) => return Ok(signal),
+                None => continue,
+            }
+        }
+    }
+
+    /// Block the current thread until one of the signals arrives or the timeout elapses.
+    ///
+    /// Returns `None` if the timeout elapsed.
+    pub fn wait_timeout(&self, timeout: Duration) -> io::Result<Option<Signal>> {
+        // Don't start over when interrupted, or repeated interruptions could keep this blocked.
+        let deadline = Instant::now().checked_add(timeout);
+
+        loop {
+            let left = match deadline {
+                Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+                None => timeout,
+            };
+            let left = libc::timespec {
+                tv_sec: left.as_secs().try_into().unwrap_or(libc::time_t::MAX),
+                tv_nsec: left.subsec_nanos() as _,
+            };
+
+            // SAFETY: `sigset` and `left` are valid.
+            let number = unsafe { libc::sigtimedwait(&self.sigset, ptr::null_mut(), &left) };
+
+            match self.check(number) {
+                Ok(Some(signal)) => return Ok(Some(signal)),
+                Ok(None) => continue,
+                Err(err) if err.raw_os_error() == Some(libc::EAGAIN) => return Ok(None),
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Get a blocking iterator over the incoming signals.
+    ///
+    /// The iterator handles errors by ending the iteration.
+    pub fn forever(&self) -> Forever<'_> {
+        Forever { signals: self }
+    }
+
+    /// Convert the result of `sigwaitinfo` into a signal.
+    ///
+    /// Returns `None` if the wait was interrupted and should be retried.
+    fn check(&self, number: std::os::raw::c_int) -> io::Result<Option<Signal>> {
+        if number == -1 {
+            let err = io::Error::last_os_error();
+            return match err.kind() {
+                io::ErrorKind::Interrupted => Ok(None),
+                _ => Err(err),
+            };
+        }
+
+        Signal::from_number(number)
+            .map(Some)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
+    }
+}
+
+/// A blocking iterator over the signals received by [`BlockingSignals`].
+///
+/// This is created by [`BlockingSignals::forever`].
+#[derive(Debug)]
+pub struct Forever<'a> {
+    /// The signals to wait for.
+    signals: &'a BlockingSignals,
+}
+
+impl Iterator for Forever<'_> {
+    type Item = Signal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.signals.wait().ok()
+    }
+}
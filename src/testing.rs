@@ -0,0 +1,184 @@
+//! Utilities for testing signal handling without sending real signals.
+
+use crate::{Signal, SignalSet};
+
+use futures_core::stream::{FusedStream, Stream};
+
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll, Waker};
+
+/// A stand-in for [`Signals`](crate::Signals) that is driven by injected signals.
+///
+/// This implements the same [`Stream`] interface as [`Signals`](crate::Signals), but no signal
+/// handlers are installed. Instead, signals are delivered through [`MockSignals::inject`], so that
+/// code that handles signals can be tested deterministically, without racing the operating
+/// system or affecting the rest of the process.
+///
+/// # Examples
+///
+/// ```
+/// use async_signal::testing::MockSignals;
+/// use async_signal::Signal;
+/// use futures_lite::prelude::*;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut signals = MockSignals::new([Signal::Term]);
+/// signals.inject(Signal::Term);
+///
+/// assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Term);
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct MockSignals {
+    /// The signals that are registered.
+    signals: SignalSet,
+
+    /// The signals that were injected but not received yet.
+    queue: Mutex<VecDeque<Signal>>,
+
+    /// The waker of the task waiting for a signal.
+    waker: Mutex<Option<Waker>>,
+}
+
+impl MockSignals {
+    /// Create a new `MockSignals` with a set of signals.
+    pub fn new<B>(signals: impl IntoIterator<Item = B>) -> Self
+    where
+        B: Borrow<Signal>,
+    {
+        Self {
+            signals: signals.into_iter().map(|signal| *signal.borrow()).collect(),
+            queue: Mutex::new(VecDeque::new()),
+            waker: Mutex::new(None),
+        }
+    }
+
+    /// Add signals to the set of signals to wait for.
+    pub fn add_signals<B>(&mut self, signals: impl IntoIterator<Item = B>)
+    where
+        B: Borrow<Signal>,
+    {
+        self.signals
+            .extend(signals.into_iter().map(|signal| *signal.borrow()));
+    }
+
+    /// Remove signals from the set of signals to wait for.
+    ///
+    /// Like with [`Signals`](crate::Signals), signals that were already injected are discarded
+    /// once they are removed.
+    pub fn remove_signals<B>(&mut self, signals: impl IntoIterator<Item = B>)
+    where
+        B: Borrow<Signal>,
+    {
+        for signal in signals {
+            self.signals.remove(*signal.borrow());
+        }
+    }
+
+    /// Deliver a signal, as if it had been received.
+    ///
+    /// Signals that aren't registered are discarded, like with [`Signals`](crate::Signals).
+    pub fn inject(&self, signal: Signal) {
+        if !self.signals.contains(signal) {
+            return;
+        }
+
+        lock(&self.queue).push_back(signal);
+        if let Some(waker) = lock(&self.waker).take() {
+            waker.wake();
+        }
+    }
+
+    /// Try to receive the next signal without waiting.
+    pub fn try_next_signal(&self) -> io::Result<Option<Signal>> {
+        let mut queue = lock(&self.queue);
+
+        while let Some(signal) = queue.pop_front() {
+            if self.signals.contains(signal) {
+                return Ok(Some(signal));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Get the number of signals that are currently registered.
+    pub fn len(&self) -> usize {
+        self.signals.len()
+    }
+
+    /// Tell whether no signals are currently registered.
+    pub fn is_empty(&self) -> bool {
+        self.signals.is_empty()
+    }
+
+    /// Poll for the next signal.
+    fn poll_signal(&self, cx: &mut Context<'_>) -> Poll<io::Result<Signal>> {
+        if let Some(signal) = self.try_next_signal()? {
+            return Poll::Ready(Ok(signal));
+        }
+
+        // Register the waker and try again, in case a signal came in in the meantime.
+        *lock(&self.waker) = Some(cx.waker().clone());
+
+        match self.try_next_signal()? {
+            Some(signal) => Poll::Ready(Ok(signal)),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Stream for MockSignals {
+    type Item = io::Result<Signal>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut &*self).poll_next(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // This stream is expected to never end.
+        (usize::MAX, None)
+    }
+}
+
+impl FusedStream for MockSignals {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        // This stream is expected to never end.
+        false
+    }
+}
+
+impl Stream for &MockSignals {
+    type Item = io::Result<Signal>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let signal = futures_core::ready!(self.poll_signal(cx))?;
+        Poll::Ready(Some(Ok(signal)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // This stream is expected to never end.
+        (usize::MAX, None)
+    }
+}
+
+impl FusedStream for &MockSignals {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        // This stream is expected to never end.
+        false
+    }
+}
+
+/// Lock a mutex, ignoring poisoning.
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -23,19 +23,25 @@
 //!
 //! Therefore, all we need to do to properly handle signals on Windows is to just listen for the
 //! `CTRL_C_EVENT` event. This is done by calling `SetConsoleCtrlHandler` with a callback function
-//! that iterates through a linked list of registered callbacks and calls them.
+//! that iterates through a linked list of registered callbacks and calls them. The callback is
+//! removed again once the last handler is unregistered, so that `Ctrl+C` terminates the process
+//! again, just like it does when no handler has been registered.
+//!
+//! Consoles also have `CTRL_BREAK_EVENT`, which has no CRT signal. It is comparable to the
+//! `SIGQUIT` sent by `Ctrl+\` on Unix, so it is handled by registering `SIGQUIT`, which is also
+//! what [`Signal::Break`](crate::Signal::Break) stands for.
 
 use async_lock::OnceCell;
 use slab::Slab;
 use windows_sys::Win32::Foundation::BOOL;
-use windows_sys::Win32::System::Console::{SetConsoleCtrlHandler, CTRL_C_EVENT};
+use windows_sys::Win32::System::Console::{SetConsoleCtrlHandler, CTRL_BREAK_EVENT, CTRL_C_EVENT};
 
 use std::io::Result;
 use std::mem;
 use std::os::raw::c_int;
 use std::sync::Mutex;
 
-use super::signum::SIGINT;
+use super::signum::{SIGINT, SIGQUIT};
 
 /// The ID of a signal handler.
 pub(crate) type SigId = usize;
@@ -49,8 +55,8 @@ pub(crate) unsafe fn register(
     signal: c_int,
     handler: impl Fn() + Send + Sync + 'static,
 ) -> Result<SigId> {
-    // If this signal isn't SIGINT, then we can't register it.
-    if signal != SIGINT {
+    // If this signal isn't SIGINT or SIGQUIT, then we can't register it.
+    if signal != SIGINT && signal != SIGQUIT {
         return Err(std::io::Error::new(
             std::io::ErrorKind::Other,
             "unsupported signal",
@@ -58,20 +64,18 @@ pub(crate) unsafe fn register(
     }
 
     // Register the handler into the global registry.
-    Ok(Registry::get()?.register(handler))
+    Registry::get().register(signal, handler)
 }
 
 /// Deregister a handler from the global registry.
 pub fn unregister(id: SigId) {
-    if let Ok(registry) = Registry::get() {
-        registry.unregister(id)
-    }
+    Registry::get().unregister(id)
 }
 
 /// The global registry of signal handlers.
 struct Registry {
-    /// The list of signal handlers.
-    handlers: Mutex<Slab<Handler>>,
+    /// The list of signal handlers, along with the signal they are registered for.
+    handlers: Mutex<Slab<(c_int, Handler)>>,
 }
 
 /// A closure that handles a signal.
@@ -79,21 +83,23 @@ type Handler = Box<dyn Fn() + Send + Sync + 'static>;
 
 impl Registry {
     /// Get the global instance of the registry.
-    fn get() -> Result<&'static Self> {
+    fn get() -> &'static Self {
         static REGISTRY: OnceCell<Registry> = OnceCell::new();
 
-        REGISTRY.get_or_try_init_blocking(|| {
-            // Register ourselves into the global registry.
-            let res = unsafe { SetConsoleCtrlHandler(Some(Self::handle_event), true as _) };
+        REGISTRY.get_or_init_blocking(|| Registry {
+            handlers: Mutex::new(Slab::new()),
+        })
+    }
 
-            if res == 0 {
-                return Err(std::io::Error::last_os_error());
-            }
+    /// Install or remove our console control handler.
+    fn set_installed(installed: bool) -> Result<()> {
+        let res = unsafe { SetConsoleCtrlHandler(Some(Self::handle_event), installed as _) };
 
-            Ok(Registry {
-                handlers: Mutex::new(Slab::new()),
-            })
-        })
+        if res == 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        Ok(())
     }
 
     /// Handle a console control event.
@@ -109,43 +115,57 @@ impl Registry {
 
         let _abort_on_drop = AbortOnDrop;
 
-        // If the event is CTRL_C_EVENT, then we should handle it.
-        if event == CTRL_C_EVENT {
-            // Get the global registry.
-            let registry = match Self::get() {
-                Ok(registry) => registry,
-                Err(_) => return false as BOOL,
-            };
+        let signal = match event {
+            CTRL_C_EVENT => SIGINT,
+            CTRL_BREAK_EVENT => SIGQUIT,
+            _ => {
+                mem::forget(_abort_on_drop);
+                return false as BOOL;
+            }
+        };
 
-            // Note that Windows runs these handlers in another thread, so there's no need to
-            // worry about async signal safety.
-            let handlers = registry.handlers.lock().unwrap_or_else(|e| e.into_inner());
+        // Get the global registry.
+        let registry = Self::get();
 
-            for handler in handlers.iter() {
-                (handler.1)();
-            }
+        // Note that Windows runs these handlers in another thread, so there's no need to
+        // worry about async signal safety.
+        let handlers = registry.handlers.lock().unwrap_or_else(|e| e.into_inner());
 
-            mem::forget(_abort_on_drop);
-            return true as BOOL;
+        let mut handled = false;
+        for (_, (handler_signal, handler)) in handlers.iter() {
+            if *handler_signal == signal {
+                handler();
+                handled = true;
+            }
         }
 
         mem::forget(_abort_on_drop);
-        false as BOOL
+
+        // If no handler is registered for this event, let the next handler, which is the
+        // default one that terminates the process, take care of it.
+        handled as BOOL
     }
 
     /// Register a handler for a signal.
-    fn register(&self, handler: impl Fn() + Send + Sync + 'static) -> usize {
-        self.handlers
-            .lock()
-            .unwrap_or_else(|e| e.into_inner())
-            .insert(Box::new(handler))
+    fn register(&self, signal: c_int, handler: impl Fn() + Send + Sync + 'static) -> Result<usize> {
+        let mut handlers = self.handlers.lock().unwrap_or_else(|e| e.into_inner());
+
+        // Install our console control handler along with the first signal handler.
+        if handlers.is_empty() {
+            Self::set_installed(true)?;
+        }
+
+        Ok(handlers.insert((signal, Box::new(handler))))
     }
 
     /// Unregister a handler for a signal.
     fn unregister(&self, id: usize) {
-        self.handlers
-            .lock()
-            .unwrap_or_else(|e| e.into_inner())
-            .try_remove(id);
+        let mut handlers = self.handlers.lock().unwrap_or_else(|e| e.into_inner());
+
+        // Remove our console control handler along with the last signal handler, so that the
+        // default behavior is restored.
+        if handlers.try_remove(id).is_some() && handlers.is_empty() {
+            let _ = Self::set_installed(false);
+        }
     }
 }
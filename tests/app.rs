@@ -0,0 +1,35 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{AppSignal, AppSignals, Signal};
+use futures_lite::{future, prelude::*};
+
+use std::io;
+
+use common::raise;
+
+#[test]
+fn names() {
+    let mut signals =
+        AppSignals::new([AppSignal::usr1("reload"), AppSignal::usr2("dump")]).unwrap();
+    assert_eq!(signals.app_signals()[0].signal(), Signal::Usr1);
+
+    raise(Signal::Usr2, 1);
+    raise(Signal::Usr1, 1);
+
+    future::block_on(async {
+        assert_eq!(signals.next().await.unwrap().unwrap(), "dump");
+        assert_eq!(signals.next().await.unwrap().unwrap(), "reload");
+    });
+}
+
+#[test]
+fn duplicate_signal() {
+    let err = AppSignals::new([
+        AppSignal::usr1("reload"),
+        AppSignal::new("rotate", Signal::Usr1),
+    ])
+    .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,23 @@
+#![cfg(all(unix, feature = "blocking"))]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use common::raise;
+
+#[test]
+fn add_signals_async() {
+    future::block_on(async {
+        let mut signals = Signals::new_empty().unwrap();
+        signals
+            .add_signals_async([Signal::Usr1, Signal::Usr2, Signal::Usr1])
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 2);
+
+        raise(Signal::Usr2, 1);
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Usr2);
+    });
+}
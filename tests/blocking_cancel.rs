@@ -0,0 +1,45 @@
+#![cfg(all(unix, feature = "blocking"))]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::future;
+
+use std::os::unix::io::AsRawFd;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use common::raise;
+
+#[test]
+fn add_signals_async_cancelled() {
+    // Use a single thread, so the registration can be held back.
+    std::env::set_var("BLOCKING_MAX_THREADS", "1");
+    let (release, wait) = mpsc::channel::<()>();
+    let busy = blocking::unblock(move || wait.recv());
+
+    future::block_on(async {
+        let mut signals = Signals::new_empty().unwrap();
+
+        {
+            let add = signals.add_signals_async([Signal::Winch]);
+            futures_lite::pin!(add);
+            assert!(future::poll_once(add.as_mut()).await.is_none());
+
+            // Let the thread pool register the handler, but drop the future before it sees that.
+            release.send(()).unwrap();
+            busy.await.unwrap();
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        assert_eq!(signals.len(), 0);
+
+        // SIGWINCH is ignored by default. A leftover handler would make the pipe readable.
+        raise(Signal::Winch, 1);
+        let mut fd = libc::pollfd {
+            fd: signals.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        assert_eq!(unsafe { libc::poll(&mut fd, 1, 0) }, 0);
+    });
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use async_signal::{Signal, Signals};
+
+#[test]
+fn break_is_quit() {
+    assert_eq!(Signal::Break, Signal::Quit);
+    assert_eq!(Signal::Break.name(), "SIGQUIT");
+    assert!(matches!(Signal::Quit, Signal::Break));
+}
+
+#[test]
+fn inject_break() {
+    let signals = Signals::new([Signal::Int, Signal::Break]).unwrap();
+
+    signals.inject(Signal::Break);
+    signals.inject(Signal::Int);
+
+    let mut received = common::drain(&signals);
+    received.sort();
+    assert_eq!(received, [Signal::Int, Signal::Break]);
+}
+
+#[cfg(unix)]
+#[test]
+fn break_on_unix() {
+    let signals = Signals::new([Signal::Break]).unwrap();
+
+    common::raise(Signal::Quit, 1);
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Break));
+}
+
+#[cfg(windows)]
+#[test]
+fn break_on_windows() {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Foundation::WAIT_OBJECT_0;
+    use windows_sys::Win32::System::Console::{GenerateConsoleCtrlEvent, CTRL_BREAK_EVENT};
+    use windows_sys::Win32::System::Threading::WaitForSingleObject;
+
+    let signals = Signals::new([Signal::Break]).unwrap();
+
+    unsafe {
+        GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, 0);
+        assert_eq!(
+            WaitForSingleObject(signals.as_raw_handle() as _, 5_000),
+            WAIT_OBJECT_0
+        );
+    }
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Break));
+}
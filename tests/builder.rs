@@ -0,0 +1,125 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use common::{drain, raise};
+
+#[test]
+fn capacity() {
+    let signals = Signals::builder()
+        .signals(Some(Signal::Usr1))
+        .capacity(4)
+        .build()
+        .unwrap();
+
+    raise(Signal::Usr1, 10);
+    assert_eq!(drain(&signals), [Signal::Usr1; 4]);
+}
+
+#[test]
+fn capacity_unbounded() {
+    let signals = Signals::builder()
+        .signals(Some(Signal::Usr2))
+        .capacity(4)
+        .unbounded(true)
+        .build()
+        .unwrap();
+
+    raise(Signal::Usr2, 10);
+    assert_eq!(drain(&signals), [Signal::Usr2; 10]);
+}
+
+#[test]
+fn coalesce() {
+    let signals = Signals::builder()
+        .signals([Signal::Hup, Signal::Winch])
+        .coalesce(true)
+        .counting(true)
+        .build()
+        .unwrap();
+
+    raise(Signal::Hup, 5);
+    raise(Signal::Winch, 2);
+    assert_eq!(drain(&signals), [Signal::Hup, Signal::Winch]);
+
+    // Once the signal has been received, it can be queued again.
+    raise(Signal::Hup, 3);
+    future::block_on(async {
+        assert_eq!((&signals).next().await.unwrap().unwrap(), Signal::Hup);
+    });
+    assert!(drain(&signals).is_empty());
+
+    assert_eq!(
+        signals.counts().collect::<Vec<_>>(),
+        [(Signal::Hup, 2), (Signal::Winch, 1)]
+    );
+}
+
+#[test]
+fn priority() {
+    let signals = Signals::builder()
+        .signals([Signal::Term, Signal::Quit])
+        .priority([Signal::Term])
+        .build()
+        .unwrap();
+
+    raise(Signal::Quit, 1);
+    raise(Signal::Term, 1);
+    assert_eq!(drain(&signals), [Signal::Term, Signal::Quit]);
+}
+
+#[test]
+fn batch_size() {
+    let signals = Signals::builder()
+        .signals([Signal::Alarm])
+        .batch_size(1)
+        .build()
+        .unwrap();
+
+    raise(Signal::Alarm, 3);
+    future::block_on(async {
+        for _ in 0..3 {
+            assert_eq!(signals.recv_all().await.unwrap(), [Signal::Alarm]);
+        }
+    });
+    assert!(signals.try_next_signal().unwrap().is_none());
+}
+
+#[test]
+fn capacity_query() {
+    assert_eq!(Signals::new_empty().unwrap().capacity(), None);
+
+    let mut signals = Signals::builder().capacity(4).build().unwrap();
+    assert_eq!(signals.capacity(), Some(4));
+
+    signals.set_capacity(None);
+    assert_eq!(signals.capacity(), None);
+}
+
+#[test]
+fn occurrences() {
+    let signals = Signals::builder()
+        .signals([Signal::Cont])
+        .coalesce(true)
+        .build()
+        .unwrap();
+
+    raise(Signal::Cont, 4);
+    future::block_on(async {
+        let mut occurrences = signals.occurrences();
+        assert_eq!(
+            occurrences.next().await.unwrap().unwrap(),
+            (Signal::Cont, 4)
+        );
+
+        raise(Signal::Cont, 1);
+        assert_eq!(
+            occurrences.next().await.unwrap().unwrap(),
+            (Signal::Cont, 1)
+        );
+    });
+    assert!(drain(&signals).is_empty());
+}
@@ -0,0 +1,62 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use common::raise;
+
+const COUNT: usize = 10_000;
+
+#[test]
+fn drop_next_futures() {
+    let mut signals = Signals::new(Some(Signal::Usr1)).unwrap();
+
+    // Make sure that signals aren't dropped because the pipe is full.
+    signals.set_unbounded(true);
+
+    let done = Arc::new(AtomicBool::new(false));
+    let flood = std::thread::spawn({
+        let done = done.clone();
+        move || {
+            for _ in 0..COUNT {
+                raise(Signal::Usr1, 1);
+            }
+
+            done.store(true, Ordering::SeqCst);
+        }
+    });
+
+    future::block_on(async {
+        let mut rng = fastrand::Rng::new();
+        let mut received = 0;
+
+        while received < COUNT {
+            // Poll a fresh future a few times, then drop it.
+            let mut next = signals.next();
+            for _ in 0..rng.usize(1..3) {
+                if let Some(signal) = future::poll_once(&mut next).await {
+                    assert_eq!(signal.unwrap().unwrap(), Signal::Usr1);
+                    received += 1;
+                    break;
+                }
+            }
+            drop(next);
+
+            // Once the flood is over, just wait for the rest.
+            if done.load(Ordering::SeqCst) && received < COUNT {
+                assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Usr1);
+                received += 1;
+            }
+        }
+
+        assert_eq!(received, COUNT);
+        assert!(future::poll_once(signals.next()).await.is_none());
+    });
+
+    flood.join().unwrap();
+}
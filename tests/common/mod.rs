@@ -0,0 +1,20 @@
+//! Helpers shared between the integration tests.
+
+#![allow(dead_code)]
+
+use async_signal::{Signal, Signals};
+
+/// Raise a signal in the current process a number of times.
+#[cfg(unix)]
+pub fn raise(signal: Signal, times: usize) {
+    for _ in 0..times {
+        unsafe {
+            libc::raise(signal as i32);
+        }
+    }
+}
+
+/// Take every signal that is currently available.
+pub fn drain(signals: &Signals) -> Vec<Signal> {
+    std::iter::from_fn(|| signals.try_next_signal().unwrap()).collect()
+}
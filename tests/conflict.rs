@@ -0,0 +1,41 @@
+#![cfg(all(unix, debug_assertions))]
+
+use async_signal::{Diagnostic, Signal, Signals};
+
+use std::mem;
+use std::ptr;
+use std::sync::{Arc, Mutex};
+
+extern "C" fn handler(_: i32) {}
+
+#[test]
+fn conflicting_handler() {
+    // Install a handler behind the crate's back.
+    unsafe {
+        let mut action: libc::sigaction = mem::zeroed();
+        action.sa_sigaction = handler as extern "C" fn(i32) as usize;
+        assert_eq!(libc::sigaction(libc::SIGUSR1, &action, ptr::null_mut()), 0);
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut signals = Signals::new_empty().unwrap();
+    signals.set_diagnostic_hook({
+        let events = events.clone();
+        move |event| events.lock().unwrap().push(event)
+    });
+
+    signals.add_signals([Signal::Usr1, Signal::Usr2]).unwrap();
+    assert_eq!(
+        *events.lock().unwrap(),
+        [Diagnostic::ConflictingHandler(Signal::Usr1)]
+    );
+
+    // The handler installed through the crate isn't reported.
+    let mut other = Signals::new_empty().unwrap();
+    other.set_diagnostic_hook({
+        let events = events.clone();
+        move |event| events.lock().unwrap().push(event)
+    });
+    other.add_signals([Signal::Usr1, Signal::Usr2]).unwrap();
+    assert_eq!(events.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,34 @@
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use std::thread;
+
+#[test]
+fn control_from_another_thread() {
+    let mut signals = Signals::new(Some(Signal::Int)).unwrap();
+    let control = signals.control_handle().unwrap();
+
+    let handle = thread::spawn(move || {
+        control.inject(Signal::Int);
+        control.inject(Signal::Int);
+    });
+
+    future::block_on(async {
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Int);
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Int);
+    });
+    handle.join().unwrap();
+}
+
+#[test]
+fn control_after_drop() {
+    let signals = Signals::new(Some(Signal::Int)).unwrap();
+    let control = signals.control_handle().unwrap();
+    let clone = control.clone();
+
+    drop(signals);
+
+    // This is a no-op once the signals are gone.
+    control.inject(Signal::Int);
+    clone.inject(Signal::Int);
+}
@@ -0,0 +1,30 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+
+use common::raise;
+
+#[test]
+fn creation_window() {
+    let mut signals = Signals::new_empty().unwrap();
+
+    // SIGURG is ignored by default, so this one runs the default disposition and is lost.
+    raise(Signal::Urg, 1);
+
+    signals.add_signals([Signal::Urg]).unwrap();
+    assert_eq!(signals.try_next_signal().unwrap(), None);
+
+    // Once the signal is added, every delivery is captured.
+    raise(Signal::Urg, 1);
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Urg));
+}
+
+#[test]
+fn captured_right_after_new() {
+    let signals = Signals::new([Signal::Winch]).unwrap();
+
+    raise(Signal::Winch, 1);
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Winch));
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use futures_lite::future;
+
+/// Send SIGINT to the current process.
+#[cfg(unix)]
+fn sigint() {
+    common::raise(async_signal::Signal::Int, 1);
+}
+
+/// Send SIGINT to the current process.
+#[cfg(windows)]
+fn sigint() {
+    unsafe {
+        windows_sys::Win32::System::Console::GenerateConsoleCtrlEvent(
+            windows_sys::Win32::System::Console::CTRL_C_EVENT,
+            0,
+        );
+    }
+}
+
+#[test]
+fn ctrl_c() {
+    future::block_on(async {
+        let mut ctrl_c = Box::pin(async_signal::ctrl_c());
+        assert!(future::poll_once(&mut ctrl_c).await.is_none());
+
+        sigint();
+        ctrl_c.await.unwrap();
+    });
+}
+
+#[cfg(unix)]
+#[test]
+fn any_of() {
+    use async_signal::Signal;
+
+    future::block_on(async {
+        let mut any_of = Box::pin(async_signal::any_of(&[Signal::Term, Signal::Hup]));
+        assert!(future::poll_once(&mut any_of).await.is_none());
+
+        common::raise(Signal::Hup, 1);
+        assert_eq!(any_of.await.unwrap(), Signal::Hup);
+    });
+}
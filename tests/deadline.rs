@@ -0,0 +1,64 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::future;
+
+use std::time::{Duration, Instant};
+
+use common::raise;
+
+#[test]
+fn poll_next_deadline() {
+    let signals = Signals::new([Signal::Int]).unwrap();
+
+    // The deadline passes without a signal.
+    let start = Instant::now();
+    let deadline = start + Duration::from_millis(50);
+    let signal = future::block_on(future::poll_fn(|cx| {
+        signals.poll_next_deadline(cx, deadline)
+    }))
+    .unwrap();
+    assert_eq!(signal, None);
+    assert!(start.elapsed() >= Duration::from_millis(50));
+
+    // A signal that is available is returned, even with a deadline in the past.
+    signals.inject(Signal::Int);
+    let signal = future::block_on(future::poll_fn(|cx| {
+        signals.poll_next_deadline(cx, deadline)
+    }))
+    .unwrap();
+    assert_eq!(signal, Some(Signal::Int));
+
+    // The timer is reused with a new deadline.
+    let deadline = Instant::now() + Duration::from_millis(20);
+    let signal = future::block_on(future::poll_fn(|cx| {
+        signals.poll_next_deadline(cx, deadline)
+    }))
+    .unwrap();
+    assert_eq!(signal, None);
+    assert!(Instant::now() >= deadline);
+}
+
+#[test]
+fn debounced() {
+    use futures_lite::prelude::*;
+
+    let signals = Signals::new([Signal::Hup]).unwrap();
+    let window = Duration::from_millis(100);
+    let mut debounced = signals.debounced(Signal::Hup, window);
+
+    for _ in 0..5 {
+        raise(Signal::Hup, 1);
+    }
+
+    // The burst results in a single emission, once the window has elapsed.
+    let start = Instant::now();
+    assert_eq!(
+        future::block_on(debounced.next()).unwrap().unwrap(),
+        Signal::Hup
+    );
+    assert!(start.elapsed() >= window);
+    assert!(future::block_on(future::poll_once(debounced.next())).is_none());
+}
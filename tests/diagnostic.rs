@@ -0,0 +1,65 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Diagnostic, Signal, Signals};
+
+use std::sync::{Arc, Mutex};
+
+use common::raise;
+
+#[test]
+fn dropped() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut signals = Signals::new(Some(Signal::Usr1)).unwrap();
+    signals.set_diagnostic_hook({
+        let events = events.clone();
+        move |event| events.lock().unwrap().push(event)
+    });
+
+    // Overflow the pipe.
+    raise(Signal::Usr1, 10_000);
+
+    // Nothing is reported until the signals are polled.
+    assert!(events.lock().unwrap().is_empty());
+
+    let mut received = 0;
+    while signals.try_next_signal().unwrap().is_some() {
+        received += 1;
+    }
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 10_000 - received);
+    assert!(events
+        .iter()
+        .all(|event| *event == Diagnostic::Dropped(Signal::Usr1)));
+}
+
+#[test]
+fn named() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut signals = Signals::builder()
+        .signals(Some(Signal::Usr2))
+        .capacity(1)
+        .build()
+        .unwrap()
+        .with_name("reload");
+    signals.set_named_diagnostic_hook({
+        let events = events.clone();
+        move |name, event| events.lock().unwrap().push((name.map(String::from), event))
+    });
+
+    raise(Signal::Usr2, 3);
+    while signals.try_next_signal().unwrap().is_some() {}
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            (
+                Some("reload".to_string()),
+                Diagnostic::Dropped(Signal::Usr2)
+            );
+            2
+        ]
+    );
+}
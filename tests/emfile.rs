@@ -0,0 +1,36 @@
+#![cfg(unix)]
+
+use async_signal::{Signal, Signals};
+
+use std::fs::File;
+use std::mem::MaybeUninit;
+
+#[test]
+fn fd_exhaustion() {
+    // Lower the file descriptor limit and use up all of them.
+    let old = unsafe {
+        let mut limit = MaybeUninit::<libc::rlimit>::uninit();
+        assert_eq!(libc::getrlimit(libc::RLIMIT_NOFILE, limit.as_mut_ptr()), 0);
+        limit.assume_init()
+    };
+    let new = libc::rlimit {
+        rlim_cur: 64.min(old.rlim_cur),
+        ..old
+    };
+    assert_eq!(unsafe { libc::setrlimit(libc::RLIMIT_NOFILE, &new) }, 0);
+
+    let mut files = Vec::new();
+    while let Ok(file) = File::open("/dev/null") {
+        files.push(file);
+    }
+
+    let err = Signals::new(Some(Signal::Usr1)).unwrap_err();
+
+    drop(files);
+    assert_eq!(unsafe { libc::setrlimit(libc::RLIMIT_NOFILE, &old) }, 0);
+
+    assert_eq!(err.raw_os_error(), Some(libc::EMFILE), "{}", err);
+
+    // Once file descriptors are available again, this works.
+    Signals::new(Some(Signal::Usr1)).unwrap();
+}
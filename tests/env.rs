@@ -0,0 +1,46 @@
+use async_signal::{Signal, Signals};
+
+use std::io;
+
+#[cfg(unix)]
+#[test]
+fn from_env() {
+    std::env::set_var("ASYNC_SIGNAL_TEST_VALID", " TERM, SIGHUP ,winch,");
+    let signals = Signals::from_env("ASYNC_SIGNAL_TEST_VALID").unwrap();
+
+    assert_eq!(signals.len(), 3);
+    for signal in [Signal::Term, Signal::Hup, Signal::Winch] {
+        assert!(signals.sig_id(signal).is_some(), "{:?}", signal);
+    }
+}
+
+#[test]
+fn from_env_int() {
+    std::env::set_var("ASYNC_SIGNAL_TEST_INT", "INT");
+    let signals = Signals::from_env("ASYNC_SIGNAL_TEST_INT").unwrap();
+    assert_eq!(signals.len(), 1);
+
+    signals.inject(Signal::Int);
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Int));
+}
+
+#[test]
+fn from_env_empty() {
+    std::env::set_var("ASYNC_SIGNAL_TEST_EMPTY", "  ");
+    let signals = Signals::from_env("ASYNC_SIGNAL_TEST_EMPTY").unwrap();
+    assert!(signals.is_empty());
+}
+
+#[test]
+fn from_env_invalid() {
+    std::env::set_var("ASYNC_SIGNAL_TEST_INVALID", "INT,BOGUS");
+    let err = Signals::from_env("ASYNC_SIGNAL_TEST_INVALID").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert!(err.to_string().contains("BOGUS"), "{}", err);
+}
+
+#[test]
+fn from_env_unset() {
+    let err = Signals::from_env("ASYNC_SIGNAL_TEST_UNSET").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
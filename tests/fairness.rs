@@ -0,0 +1,25 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+
+use common::raise;
+
+#[test]
+fn pipe_and_overflow() {
+    let mut signals = Signals::new([Signal::Usr1, Signal::Usr2]).unwrap();
+    signals.set_unbounded(true);
+    signals.set_capacity(Some(1));
+
+    // The first signal goes into the pipe, the second one into the overflow.
+    raise(Signal::Usr1, 1);
+    raise(Signal::Usr2, 1);
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Usr1));
+
+    // Keep the pipe busy; the overflow must still be serviced.
+    raise(Signal::Usr1, 1);
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Usr2));
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Usr1));
+    assert_eq!(signals.try_next_signal().unwrap(), None);
+}
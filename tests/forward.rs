@@ -0,0 +1,27 @@
+#![cfg(all(unix, feature = "async-channel"))]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::future;
+
+use std::thread;
+
+use common::raise;
+
+#[test]
+fn forward_to() {
+    let signals = Signals::new([Signal::Usr1, Signal::Usr2]).unwrap();
+    let (tx, rx) = async_channel::unbounded();
+    let forward = thread::spawn(move || future::block_on(signals.forward_to(tx)));
+
+    raise(Signal::Usr1, 1);
+    raise(Signal::Usr2, 1);
+    assert_eq!(rx.recv_blocking().unwrap(), Signal::Usr1);
+    assert_eq!(rx.recv_blocking().unwrap(), Signal::Usr2);
+
+    // Once the channel is closed, forwarding stops.
+    drop(rx);
+    raise(Signal::Usr1, 1);
+    forward.join().unwrap().unwrap();
+}
@@ -0,0 +1,26 @@
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn inject() {
+    let signals = Signals::new(Some(Signal::Int)).unwrap();
+    assert!(signals.try_next_signal().unwrap().is_none());
+
+    // Wake up a waiting task from another thread.
+    thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(Duration::from_millis(50));
+            signals.inject(Signal::Int);
+        });
+
+        let signal = future::block_on((&signals).next());
+        assert_eq!(signal.unwrap().unwrap(), Signal::Int);
+    });
+
+    // Signals that aren't registered are discarded.
+    signals.inject(Signal::Term);
+    assert!(signals.try_next_signal().unwrap().is_none());
+}
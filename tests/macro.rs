@@ -0,0 +1,13 @@
+use async_signal::{signals, Signal};
+
+#[test]
+fn signals_macro() {
+    assert_eq!(signals![Term], [Signal::Term]);
+    assert_eq!(
+        signals![Term, Int, Hup,],
+        [Signal::Term, Signal::Int, Signal::Hup]
+    );
+
+    let empty: [Signal; 0] = signals![];
+    assert!(empty.is_empty());
+}
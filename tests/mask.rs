@@ -0,0 +1,65 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, SignalSet, Signals};
+
+use std::ptr;
+
+use common::raise;
+
+/// Change the signal mask of the current thread.
+fn set_mask(how: i32, signals: SignalSet) {
+    let sigset: libc::sigset_t = signals.into();
+
+    unsafe {
+        assert_eq!(libc::pthread_sigmask(how, &sigset, ptr::null_mut()), 0);
+    }
+}
+
+#[test]
+fn delivery_is_reliable() {
+    let signals = Signals::new([Signal::Usr1, Signal::Usr2]).unwrap();
+    assert!(signals.delivery_is_reliable());
+
+    set_mask(libc::SIG_BLOCK, Signal::Usr2.into());
+    assert!(!signals.delivery_is_reliable());
+
+    set_mask(libc::SIG_UNBLOCK, Signal::Usr2.into());
+    assert!(signals.delivery_is_reliable());
+}
+
+#[test]
+fn os_pending() {
+    let signals = Signals::new([Signal::Hup, Signal::Alarm]).unwrap();
+    assert!(signals.os_pending().unwrap().is_empty());
+
+    // A blocked signal stays pending.
+    set_mask(libc::SIG_BLOCK, Signal::Hup.into());
+    raise(Signal::Hup, 1);
+    assert_eq!(signals.os_pending().unwrap(), Signal::Hup.into());
+    assert_eq!(signals.try_next_signal().unwrap(), None);
+
+    // Once it is unblocked, it is delivered.
+    set_mask(libc::SIG_UNBLOCK, Signal::Hup.into());
+    assert!(signals.os_pending().unwrap().is_empty());
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Hup));
+}
+
+#[test]
+fn no_thread_affinity() {
+    let signals = Signals::new([Signal::Term]).unwrap();
+
+    // The thread that reads the signals blocks them, the one that receives them doesn't.
+    std::thread::scope(|s| {
+        let reader = s.spawn(|| {
+            set_mask(libc::SIG_BLOCK, Signal::Term.into());
+            futures_lite::future::block_on(signals.wait_for_any(&[Signal::Term])).unwrap()
+        });
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        raise(Signal::Term, 1);
+
+        assert_eq!(reader.join().unwrap(), Signal::Term);
+    });
+}
@@ -0,0 +1,68 @@
+#![cfg(all(unix, feature = "mio"))]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use mio::{Events, Interest, Poll, Token};
+
+use std::time::Duration;
+
+use common::raise;
+
+#[test]
+fn mio_poll() {
+    let mut signals = Signals::new(Some(Signal::Usr1)).unwrap();
+    let mut poll = Poll::new().unwrap();
+    let mut events = Events::with_capacity(8);
+
+    poll.registry()
+        .register(&mut signals, Token(0), Interest::READABLE)
+        .unwrap();
+
+    // Nothing should be ready yet.
+    poll.poll(&mut events, Some(Duration::from_millis(10)))
+        .unwrap();
+    assert!(events.is_empty());
+    assert!(signals.try_next_signal().unwrap().is_none());
+
+    raise(Signal::Usr1, 1);
+
+    poll.poll(&mut events, Some(Duration::from_secs(5)))
+        .unwrap();
+    assert!(events.iter().any(|event| event.token() == Token(0)));
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Usr1));
+    assert!(signals.try_next_signal().unwrap().is_none());
+
+    poll.registry().deregister(&mut signals).unwrap();
+}
+
+#[test]
+fn mio_drain_overflow() {
+    let mut signals = Signals::new(Some(Signal::Usr2)).unwrap();
+    signals.set_capacity(Some(2));
+    signals.set_unbounded(true);
+
+    let mut poll = Poll::new().unwrap();
+    let mut events = Events::with_capacity(8);
+    poll.registry()
+        .register(&mut signals, Token(0), Interest::READABLE)
+        .unwrap();
+
+    for _ in 0..5 {
+        raise(Signal::Usr2, 1);
+    }
+
+    // A single readiness event is enough to receive all of them.
+    poll.poll(&mut events, Some(Duration::from_secs(5)))
+        .unwrap();
+    assert!(events.iter().any(|event| event.token() == Token(0)));
+
+    let mut count = 0;
+    while let Some(signal) = signals.try_next_signal().unwrap() {
+        assert_eq!(signal, Signal::Usr2);
+        count += 1;
+    }
+    assert_eq!(count, 5);
+
+    poll.registry().deregister(&mut signals).unwrap();
+}
@@ -0,0 +1,16 @@
+use async_signal::Signals;
+
+#[test]
+fn name_in_debug() {
+    let signals = Signals::new_empty().unwrap().with_name("reload");
+    assert_eq!(signals.name(), Some("reload"));
+    assert!(format!("{:?}", signals).contains("\"reload\""));
+
+    let signals = Signals::new_empty()
+        .unwrap()
+        .with_name(format!("worker-{}", 3));
+    assert_eq!(signals.name(), Some("worker-3"));
+    assert!(format!("{:?}", signals).contains("\"worker-3\""));
+
+    assert_eq!(Signals::new_empty().unwrap().name(), None);
+}
@@ -0,0 +1,35 @@
+#![cfg(all(unix, feature = "nix"))]
+
+use async_signal::Signal;
+use nix::sys::signal::Signal as NixSignal;
+
+use std::convert::TryFrom;
+
+#[test]
+fn from_nix() {
+    for (nix, signal) in [
+        (NixSignal::SIGHUP, Signal::Hup),
+        (NixSignal::SIGINT, Signal::Int),
+        (NixSignal::SIGTERM, Signal::Term),
+        (NixSignal::SIGCHLD, Signal::Child),
+        (NixSignal::SIGWINCH, Signal::Winch),
+    ] {
+        assert_eq!(Signal::try_from(nix).unwrap(), signal);
+        assert_eq!(NixSignal::try_from(signal).unwrap(), nix);
+    }
+}
+
+#[test]
+fn every_signal_converts() {
+    for signal in (0..64).filter_map(Signal::from_signal_hook) {
+        let nix = NixSignal::try_from(signal).unwrap();
+        assert_eq!(Signal::try_from(nix).unwrap(), signal);
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn unsupported() {
+    let err = Signal::try_from(NixSignal::SIGPWR).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,28 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use common::raise;
+
+#[test]
+fn observe() {
+    let mut signals = Signals::new([Signal::Usr1, Signal::Usr2]).unwrap();
+    let mut observer = signals.observe().unwrap();
+
+    raise(Signal::Usr1, 1);
+    raise(Signal::Usr2, 1);
+
+    future::block_on(async {
+        // Both the consumer and the observer see every signal.
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Usr1);
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Usr2);
+        assert_eq!(observer.next().await, Some(Signal::Usr1));
+        assert_eq!(observer.next().await, Some(Signal::Usr2));
+
+        assert!(future::poll_once(signals.next()).await.is_none());
+        assert!(future::poll_once(observer.next()).await.is_none());
+    });
+}
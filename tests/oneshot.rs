@@ -0,0 +1,56 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use std::os::unix::process::ExitStatusExt;
+use std::process::Command;
+
+use common::raise;
+
+/// Set in the child process spawned by `oneshot_then_default`.
+const CHILD_VAR: &str = "ASYNC_SIGNAL_ONESHOT_CHILD";
+
+#[test]
+fn oneshot_then_default() {
+    if std::env::var_os(CHILD_VAR).is_some() {
+        let mut signals = Signals::new_empty().unwrap();
+        signals.add_oneshot(Signal::Term).unwrap();
+
+        // The first one is caught.
+        raise(Signal::Term, 1);
+        future::block_on(async {
+            assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Term);
+        });
+
+        // The second one should terminate the process.
+        raise(Signal::Term, 1);
+        std::process::exit(0);
+    }
+
+    // Run this test again in a child process, since it is supposed to die.
+    let status = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "oneshot_then_default", "--test-threads=1"])
+        .env(CHILD_VAR, "1")
+        .status()
+        .unwrap();
+    assert_eq!(status.signal(), Some(libc::SIGTERM));
+}
+
+#[test]
+fn oneshot_delivers_once() {
+    let mut signals = Signals::new_empty().unwrap();
+    signals.add_oneshot(Signal::Winch).unwrap();
+
+    // SIGWINCH is ignored by default, so the process survives the later deliveries.
+    for _ in 0..3 {
+        raise(Signal::Winch, 1);
+    }
+
+    future::block_on(async {
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Winch);
+    });
+    assert!(signals.try_next_signal().unwrap().is_none());
+}
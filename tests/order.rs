@@ -0,0 +1,51 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use common::raise;
+
+const SEQUENCE: [Signal; 6] = [
+    Signal::Term,
+    Signal::Hup,
+    Signal::Usr1,
+    Signal::Hup,
+    Signal::Usr2,
+    Signal::Term,
+];
+
+fn raise_sequence() {
+    for signal in SEQUENCE {
+        raise(signal, 1);
+    }
+}
+
+#[test]
+fn order_is_preserved() {
+    let mut signals =
+        Signals::new([Signal::Term, Signal::Hup, Signal::Usr1, Signal::Usr2]).unwrap();
+
+    future::block_on(async {
+        // One at a time.
+        raise_sequence();
+        for expected in SEQUENCE {
+            assert_eq!(signals.next().await.unwrap().unwrap(), expected);
+        }
+
+        // In a batch.
+        raise_sequence();
+        assert_eq!(signals.recv_all().await.unwrap(), SEQUENCE);
+
+        // In several batches.
+        raise_sequence();
+        let mut received = Vec::new();
+        while received.len() < SEQUENCE.len() {
+            future::poll_fn(|cx| signals.poll_next_batch(cx, &mut received, 4))
+                .await
+                .unwrap();
+        }
+        assert_eq!(received, SEQUENCE);
+    });
+}
@@ -0,0 +1,62 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use std::os::unix::process::ExitStatusExt;
+use std::process::Command;
+
+use common::raise;
+
+/// Set in the child process spawned by `passthrough_terminates`.
+const CHILD_VAR: &str = "ASYNC_SIGNAL_PASSTHROUGH_CHILD";
+
+#[test]
+fn passthrough_terminates() {
+    if std::env::var_os(CHILD_VAR).is_some() {
+        let mut signals = Signals::new_empty().unwrap();
+        signals.add_passthrough_signals(Some(Signal::Term)).unwrap();
+
+        raise(Signal::Term, 1);
+
+        // The default handler should have terminated the process.
+        std::process::exit(0);
+    }
+
+    // Run this test again in a child process, since it is supposed to die.
+    let status = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "passthrough_terminates", "--test-threads=1"])
+        .env(CHILD_VAR, "1")
+        .status()
+        .unwrap();
+    assert_eq!(status.signal(), Some(libc::SIGTERM));
+}
+
+#[test]
+fn passthrough_ignored() {
+    let mut signals = Signals::new_empty().unwrap();
+    signals
+        .add_passthrough_signals(Some(Signal::Winch))
+        .unwrap();
+
+    // The default action of SIGWINCH is to do nothing, so we still get notified.
+    raise(Signal::Winch, 1);
+
+    future::block_on(async {
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Winch);
+    });
+}
+
+#[test]
+fn on_then_default() {
+    let signals = Signals::new([Signal::Urg]).unwrap();
+
+    raise(Signal::Urg, 1);
+
+    // The default action of SIGURG is to do nothing, so this returns after the cleanup.
+    let mut cleaned_up = false;
+    future::block_on(signals.on_then_default(Signal::Urg, || cleaned_up = true)).unwrap();
+    assert!(cleaned_up);
+}
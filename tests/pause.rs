@@ -0,0 +1,30 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use common::raise;
+
+#[test]
+fn pause_and_resume() {
+    let mut signals = Signals::new([Signal::Usr1, Signal::Usr2]).unwrap();
+
+    signals.pause_delivery();
+    raise(Signal::Usr1, 2);
+    raise(Signal::Usr2, 1);
+    assert_eq!(signals.try_next_signal().unwrap(), None);
+
+    // Held signals are merged, like the kernel's pending signals.
+    signals.resume_delivery();
+    future::block_on(async {
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Usr1);
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Usr2);
+        assert!(future::poll_once(signals.next()).await.is_none());
+    });
+
+    // Signals are delivered as usual afterwards.
+    raise(Signal::Usr1, 1);
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Usr1));
+}
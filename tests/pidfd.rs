@@ -0,0 +1,20 @@
+#![cfg(target_os = "linux")]
+
+use async_signal::ChildSignal;
+use futures_lite::future;
+
+use std::process::Command;
+
+#[test]
+fn child_exit() {
+    let mut child = Command::new("sleep").arg("0.1").spawn().unwrap();
+    let signal = ChildSignal::new(child.id() as i32).unwrap();
+
+    future::block_on(async {
+        assert!(future::poll_once(signal.wait()).await.is_none());
+        signal.wait().await.unwrap();
+    });
+
+    // The child has exited, so this returns immediately.
+    assert!(child.try_wait().unwrap().unwrap().success());
+}
@@ -0,0 +1,84 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{QueueFullPolicy, Signal, Signals};
+
+use std::io;
+
+use common::{drain, raise};
+
+#[test]
+fn drop_newest() {
+    let mut signals = Signals::new([Signal::Hup, Signal::Winch, Signal::Urg]).unwrap();
+    signals.set_capacity(Some(2));
+    signals
+        .set_queue_full_policy(QueueFullPolicy::DropNewest)
+        .unwrap();
+
+    raise(Signal::Hup, 1);
+    raise(Signal::Winch, 1);
+    raise(Signal::Urg, 1);
+    assert_eq!(drain(&signals), [Signal::Hup, Signal::Winch]);
+}
+
+#[test]
+fn drop_oldest() {
+    let mut signals = Signals::new([Signal::Usr1, Signal::Usr2, Signal::Alarm]).unwrap();
+    signals.set_capacity(Some(2));
+    signals
+        .set_queue_full_policy(QueueFullPolicy::DropOldest)
+        .unwrap();
+
+    raise(Signal::Usr1, 1);
+    raise(Signal::Usr2, 1);
+    raise(Signal::Alarm, 1);
+    assert_eq!(drain(&signals), [Signal::Usr2, Signal::Alarm]);
+
+    // There is room again afterwards.
+    raise(Signal::Usr1, 1);
+    assert_eq!(drain(&signals), [Signal::Usr1]);
+}
+
+#[test]
+fn dropped_count() {
+    let signals = Signals::builder()
+        .signals(Some(Signal::Prof))
+        .capacity(4)
+        .build()
+        .unwrap();
+    assert_eq!(signals.dropped_count(), 0);
+
+    raise(Signal::Prof, 10);
+    assert_eq!(signals.dropped_count(), 6);
+    assert_eq!(drain(&signals), [Signal::Prof; 4]);
+
+    raise(Signal::Prof, 5);
+    assert_eq!(drain(&signals), [Signal::Prof; 4]);
+    assert_eq!(signals.dropped_count(), 7);
+}
+
+#[test]
+fn dropped_count_oldest() {
+    let mut signals = Signals::builder()
+        .signals(Some(Signal::Vtalarm))
+        .capacity(4)
+        .build()
+        .unwrap();
+    signals
+        .set_queue_full_policy(QueueFullPolicy::DropOldest)
+        .unwrap();
+
+    raise(Signal::Vtalarm, 10);
+    assert_eq!(signals.dropped_count(), 6);
+    assert_eq!(drain(&signals), [Signal::Vtalarm; 4]);
+}
+
+#[test]
+fn block() {
+    let mut signals = Signals::new_empty().unwrap();
+    let err = signals
+        .set_queue_full_policy(QueueFullPolicy::Block)
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+}
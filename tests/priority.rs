@@ -0,0 +1,65 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use common::{drain, raise};
+
+#[test]
+fn priority() {
+    let mut signals = Signals::new([Signal::Hup, Signal::Term, Signal::Usr1]).unwrap();
+    signals.set_priority([Signal::Term, Signal::Hup]);
+
+    raise(Signal::Usr1, 1);
+    raise(Signal::Hup, 1);
+    raise(Signal::Usr1, 1);
+    raise(Signal::Term, 1);
+
+    future::block_on(async {
+        let mut received = Vec::new();
+        for _ in 0..4 {
+            received.push(signals.next().await.unwrap().unwrap());
+        }
+
+        assert_eq!(
+            received,
+            [Signal::Term, Signal::Hup, Signal::Usr1, Signal::Usr1]
+        );
+    });
+
+    // Without an order, signals are returned as they arrive.
+    signals.set_priority(None::<Signal>);
+
+    raise(Signal::Usr1, 1);
+    raise(Signal::Term, 1);
+
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Usr1));
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Term));
+    assert_eq!(signals.try_next_signal().unwrap(), None);
+}
+
+#[test]
+fn round_robin() {
+    let signals = Signals::builder()
+        .signals([Signal::Usr2, Signal::Winch, Signal::Urg])
+        .round_robin(true)
+        .build()
+        .unwrap();
+
+    // Flood one signal, with a couple of others in between.
+    raise(Signal::Usr2, 10);
+    raise(Signal::Winch, 1);
+    raise(Signal::Urg, 1);
+    raise(Signal::Usr2, 10);
+
+    let received = drain(&signals);
+    assert_eq!(received.len(), 22);
+
+    // The other signals are served within the first round.
+    for signal in [Signal::Usr2, Signal::Winch, Signal::Urg] {
+        assert!(received[..3].contains(&signal), "{:?}", received);
+    }
+    assert!(received[3..].iter().all(|signal| *signal == Signal::Usr2));
+}
@@ -0,0 +1,71 @@
+use async_signal::{Signal, Signals};
+use futures_lite::future;
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::thread;
+use std::time::Duration;
+
+/// A future built on the readiness primitive.
+struct Wait<'a>(&'a Signals);
+
+impl Future for Wait<'_> {
+    type Output = io::Result<Signal>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.register_readiness(cx.waker());
+
+        match self.0.try_next_signal()? {
+            Some(signal) => Poll::Ready(Ok(signal)),
+            None => Poll::Pending,
+        }
+    }
+}
+
+#[test]
+fn register_readiness() {
+    let signals = Signals::new(Some(Signal::Int)).unwrap();
+    assert!(future::block_on(future::poll_once(Wait(&signals))).is_none());
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(Duration::from_millis(50));
+            signals.inject(Signal::Int);
+        });
+
+        assert_eq!(future::block_on(Wait(&signals)).unwrap(), Signal::Int);
+    });
+}
+
+#[test]
+fn readable() {
+    let signals = Signals::new(Some(Signal::Int)).unwrap();
+    assert!(future::block_on(future::poll_once(signals.readable())).is_none());
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(Duration::from_millis(50));
+            signals.inject(Signal::Int);
+        });
+
+        future::block_on(signals.readable()).unwrap();
+    });
+
+    // The signal is still there, and stays there until it is taken.
+    future::block_on(signals.readable()).unwrap();
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Int));
+    assert_eq!(signals.try_next_signal().unwrap(), None);
+}
+
+#[cfg(unix)]
+#[test]
+fn as_async_ref() {
+    let signals = Signals::new(Some(Signal::Int)).unwrap();
+    signals.inject(Signal::Int);
+
+    future::block_on(signals.as_async_ref().readable()).unwrap();
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Int));
+    assert!(future::block_on(future::poll_once(signals.as_async_ref().readable())).is_none());
+}
@@ -0,0 +1,57 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use common::raise;
+
+#[test]
+fn no_delivery_after_remove() {
+    // `SIGUSR2` is never removed, and marks which notifications were sent after a point. With
+    // coalescing, the flood of `SIGUSR1` can't fill the pipe and drop the marker.
+    let mut signals = Signals::new([Signal::Usr1, Signal::Usr2]).unwrap();
+    signals.set_coalescing(true);
+
+    let done = Arc::new(AtomicBool::new(false));
+    let raiser = std::thread::spawn({
+        let done = done.clone();
+        move || {
+            while !done.load(Ordering::SeqCst) {
+                raise(Signal::Usr1, 1);
+            }
+        }
+    });
+
+    for _ in 0..200 {
+        signals.remove_signals(Some(Signal::Usr1)).unwrap();
+
+        // Nothing should be delivered once the signal is removed, not even notifications that
+        // were queued before.
+        raise(Signal::Usr2, 1);
+        assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Usr2));
+
+        signals.add_signals(Some(Signal::Usr1)).unwrap();
+
+        // Skip the notifications that may have been sent before the signal was added again.
+        raise(Signal::Usr2, 1);
+        loop {
+            match signals.try_next_signal().unwrap() {
+                Some(Signal::Usr2) => break,
+                Some(signal) => assert_eq!(signal, Signal::Usr1),
+                None => std::thread::yield_now(),
+            }
+        }
+
+        // Wait until the signal is being delivered again.
+        while signals.try_next_signal().unwrap() != Some(Signal::Usr1) {
+            std::thread::yield_now();
+        }
+    }
+
+    done.store(true, Ordering::SeqCst);
+    raiser.join().unwrap();
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "futures-sink")]
+
+use async_signal::{Signal, Signals};
+use futures::{SinkExt, StreamExt};
+use futures_lite::future;
+
+#[test]
+fn remove_through_sink() {
+    let mut signals = Signals::new([Signal::Int, Signal::Break]).unwrap();
+
+    future::block_on(async {
+        let commands = futures::stream::iter([Signal::Break, Signal::Term]).map(Ok);
+        commands.forward(signals.remover()).await.unwrap();
+    });
+    assert_eq!(signals.len(), 1);
+
+    // Deliveries of the removed signal stop, the others keep coming.
+    signals.inject(Signal::Break);
+    signals.inject(Signal::Int);
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Int));
+    assert_eq!(signals.try_next_signal().unwrap(), None);
+
+    future::block_on(signals.remover().send(Signal::Int)).unwrap();
+    assert!(signals.is_empty());
+}
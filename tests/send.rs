@@ -0,0 +1,35 @@
+#![cfg(unix)]
+
+use async_signal::Signal;
+
+use std::os::unix::process::{CommandExt, ExitStatusExt};
+use std::process::Command;
+
+#[test]
+fn send() {
+    let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+    async_signal::send(child.id() as i32, Signal::Term).unwrap();
+
+    let status = child.wait().unwrap();
+    assert_eq!(status.signal(), Some(libc::SIGTERM));
+}
+
+#[test]
+fn send_to_group() {
+    // Put the child into its own process group.
+    let mut child = Command::new("sleep")
+        .arg("10")
+        .process_group(0)
+        .spawn()
+        .unwrap();
+    async_signal::send_to_group(child.id() as i32, Signal::Hup).unwrap();
+
+    let status = child.wait().unwrap();
+    assert_eq!(status.signal(), Some(libc::SIGHUP));
+}
+
+#[test]
+fn send_to_missing_process() {
+    let err = async_signal::send(i32::MAX, Signal::Term).unwrap_err();
+    assert_eq!(err.raw_os_error(), Some(libc::ESRCH));
+}
@@ -0,0 +1,101 @@
+use async_signal::{Signal, SignalSet};
+
+#[test]
+fn set_operations() {
+    let mut a = SignalSet::new();
+    assert!(a.is_empty());
+    assert!(a.insert(Signal::Int));
+    assert!(a.insert(Signal::Term));
+    assert!(!a.insert(Signal::Term));
+    assert_eq!(a.len(), 2);
+    assert!(a.contains(Signal::Int));
+    assert!(!a.contains(Signal::Hup));
+
+    let b: SignalSet = [Signal::Term, Signal::Hup].iter().copied().collect();
+
+    let union = a.union(&b);
+    assert_eq!(union.len(), 3);
+    assert!(union.contains(Signal::Int));
+    assert!(union.contains(Signal::Term));
+    assert!(union.contains(Signal::Hup));
+
+    assert_eq!(a.intersection(&b), SignalSet::from(Signal::Term));
+    assert_eq!(a.difference(&b), SignalSet::from(Signal::Int));
+
+    assert!(a.remove(Signal::Int));
+    assert!(!a.remove(Signal::Int));
+    assert_eq!(a.iter().collect::<Vec<_>>(), [Signal::Term]);
+}
+
+#[test]
+fn iter_order() {
+    let set: SignalSet = [Signal::Term, Signal::Int, Signal::Hup]
+        .iter()
+        .copied()
+        .collect();
+
+    // Signals are yielded in order of signal number.
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        [Signal::Hup, Signal::Int, Signal::Term]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn sigset_round_trip() {
+    let set: SignalSet = [Signal::Usr1, Signal::Child, Signal::Term]
+        .iter()
+        .copied()
+        .collect();
+    let sigset = libc::sigset_t::from(set);
+
+    unsafe {
+        assert_eq!(libc::sigismember(&sigset, libc::SIGUSR1), 1);
+        assert_eq!(libc::sigismember(&sigset, libc::SIGCHLD), 1);
+        assert_eq!(libc::sigismember(&sigset, libc::SIGTERM), 1);
+        assert_eq!(libc::sigismember(&sigset, libc::SIGINT), 0);
+    }
+
+    assert_eq!(SignalSet::from(&sigset), set);
+}
+
+#[cfg(unix)]
+#[test]
+fn signals_from_set() {
+    let set = SignalSet::from(Signal::Usr2);
+    let mut signals = async_signal::Signals::new(set).unwrap();
+    signals.remove_signals(set).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn signals_from_sigset() {
+    let set: SignalSet = [Signal::Hup, Signal::Winch].iter().copied().collect();
+    let sigset = libc::sigset_t::from(set);
+    let signals = async_signal::Signals::from_sigset(&sigset).unwrap();
+
+    assert_eq!(signals.len(), 2);
+    assert!(signals.sig_id(Signal::Hup).is_some());
+    assert!(signals.sig_id(Signal::Winch).is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn iter_sigset() {
+    let sigset = unsafe {
+        let mut sigset = std::mem::MaybeUninit::<libc::sigset_t>::uninit();
+        libc::sigemptyset(sigset.as_mut_ptr());
+        libc::sigaddset(sigset.as_mut_ptr(), libc::SIGTERM);
+        libc::sigaddset(sigset.as_mut_ptr(), libc::SIGHUP);
+        libc::sigaddset(sigset.as_mut_ptr(), libc::SIGCHLD);
+        sigset.assume_init()
+    };
+
+    let mut expected = vec![Signal::Hup, Signal::Term, Signal::Child];
+    expected.sort_by_key(|signal| *signal as i32);
+    assert_eq!(Signal::iter_set(&sigset).collect::<Vec<_>>(), expected);
+
+    let empty = libc::sigset_t::from(SignalSet::new());
+    assert_eq!(Signal::iter_set(&empty).count(), 0);
+}
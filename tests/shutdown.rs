@@ -0,0 +1,60 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::future;
+
+use common::raise;
+
+#[test]
+fn shutdown() {
+    let signals = Signals::new([Signal::Usr1, Signal::Usr2]).unwrap();
+
+    raise(Signal::Usr2, 1);
+    raise(Signal::Usr1, 1);
+
+    let pending = future::block_on(signals.shutdown()).unwrap();
+    assert_eq!(pending, [Signal::Usr2, Signal::Usr1]);
+}
+
+#[test]
+fn drain_to_vec() {
+    let signals = Signals::new([Signal::Winch, Signal::Urg]).unwrap();
+    assert!(signals.drain_to_vec().is_empty());
+
+    raise(Signal::Urg, 1);
+    raise(Signal::Winch, 1);
+    raise(Signal::Urg, 1);
+
+    assert_eq!(
+        signals.drain_to_vec(),
+        [Signal::Urg, Signal::Winch, Signal::Urg]
+    );
+    assert!(signals.drain_to_vec().is_empty());
+}
+
+#[test]
+fn drain_fd_into_queue() {
+    use std::os::unix::io::AsRawFd;
+
+    let signals = Signals::new([Signal::Hup, Signal::Term]).unwrap();
+
+    raise(Signal::Term, 1);
+    raise(Signal::Hup, 1);
+    assert_eq!(signals.drain_fd_into_queue().unwrap(), 2);
+
+    // The pipe is empty now.
+    let mut pollfd = libc::pollfd {
+        fd: signals.as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    assert_eq!(unsafe { libc::poll(&mut pollfd, 1, 0) }, 0);
+
+    // The signals are still returned, from the queue.
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Term));
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Hup));
+    assert_eq!(signals.try_next_signal().unwrap(), None);
+    assert_eq!(signals.drain_fd_into_queue().unwrap(), 0);
+}
@@ -1,12 +1,12 @@
+mod common;
+
 use async_signal::{Signal, Signals};
 use futures_lite::{future, prelude::*};
 
 /// Send SIGINT to the current process.
 #[cfg(unix)]
 fn sigint() {
-    unsafe {
-        libc::raise(libc::SIGINT);
-    }
+    common::raise(Signal::Int, 1);
 }
 
 /// Send SIGINT to the current process.
@@ -0,0 +1,155 @@
+use async_signal::Signal;
+
+#[test]
+fn from_short_name() {
+    assert_eq!(Signal::from_short_name("term"), Some(Signal::Term));
+    assert_eq!(Signal::from_short_name("HUP"), Some(Signal::Hup));
+    assert_eq!(Signal::from_short_name("Alarm"), Some(Signal::Alarm));
+
+    assert_eq!(Signal::from_short_name("SIGTERM"), None);
+    assert_eq!(Signal::from_short_name("15"), None);
+    assert_eq!(Signal::from_short_name(""), None);
+}
+
+#[test]
+fn parse_kill_arg() {
+    for arg in [
+        "TERM", "SIGTERM", "15", "-15", "-TERM", "-SIGTERM", "term", "sigterm",
+    ] {
+        assert_eq!(Signal::parse_kill_arg(arg), Some(Signal::Term), "{}", arg);
+    }
+
+    // Conventional names are used, not the variant names.
+    assert_eq!(Signal::parse_kill_arg("ALRM"), Some(Signal::Alarm));
+    assert_eq!(Signal::parse_kill_arg("-CHLD"), Some(Signal::Child));
+    assert_eq!(Signal::parse_kill_arg("ALARM"), None);
+
+    for arg in [
+        "",
+        "-",
+        "--15",
+        "--TERM",
+        "+15",
+        "SIG",
+        "SIGSIGTERM",
+        "TERM ",
+        "1.5",
+        "9999",
+        "garbage",
+    ] {
+        assert_eq!(Signal::parse_kill_arg(arg), None, "{}", arg);
+    }
+}
+
+#[test]
+fn can_catch() {
+    assert!(!Signal::Kill.can_catch());
+    assert!(!Signal::Stop.can_catch());
+
+    for signal in [
+        Signal::Hup,
+        Signal::Int,
+        Signal::Term,
+        Signal::Usr1,
+        Signal::Child,
+        Signal::Tstp,
+    ] {
+        assert!(signal.can_catch(), "{:?}", signal);
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn signal_hook_consts() {
+    use signal_hook::consts::*;
+
+    for (number, signal) in [
+        (SIGHUP, Signal::Hup),
+        (SIGINT, Signal::Int),
+        (SIGQUIT, Signal::Quit),
+        (SIGKILL, Signal::Kill),
+        (SIGUSR1, Signal::Usr1),
+        (SIGUSR2, Signal::Usr2),
+        (SIGALRM, Signal::Alarm),
+        (SIGTERM, Signal::Term),
+        (SIGCHLD, Signal::Child),
+        (SIGCONT, Signal::Cont),
+        (SIGSTOP, Signal::Stop),
+        (SIGWINCH, Signal::Winch),
+        (SIGIO, Signal::Io),
+        (SIGSYS, Signal::Sys),
+    ] {
+        assert_eq!(Signal::from_signal_hook(number), Some(signal));
+        assert_eq!(signal as i32, number);
+    }
+
+    assert_eq!(Signal::from_signal_hook(0), None);
+}
+
+#[cfg(unix)]
+#[test]
+fn eq_number() {
+    assert_eq!(Signal::Term, libc::SIGTERM);
+    assert_eq!(libc::SIGTERM, Signal::Term);
+    assert_eq!(Signal::Child, libc::SIGCHLD);
+
+    assert_ne!(Signal::Term, libc::SIGINT);
+    assert_ne!(libc::SIGINT, Signal::Term);
+    assert_ne!(Signal::Hup, 0);
+}
+
+#[cfg(unix)]
+#[test]
+fn handle_default() {
+    // These are ignored by default, so the test process survives.
+    Signal::Winch.handle_default().unwrap();
+    Signal::Child.handle_default().unwrap();
+    Signal::Urg.handle_default().unwrap();
+}
+
+#[test]
+fn names() {
+    assert_eq!(Signal::Int.name(), "SIGINT");
+    assert_eq!(Signal::Int.short_name(), "INT");
+    assert_eq!(Signal::Alarm.name(), "SIGALRM");
+    assert_eq!(Signal::Alarm.short_name(), "ALRM");
+    assert_eq!(Signal::Child.short_name(), "CHLD");
+    assert_eq!(Signal::Io.short_name(), "IO");
+
+    for signal in (0..64).filter_map(Signal::from_signal_hook) {
+        let name = signal.name();
+        assert!(name.starts_with("SIG"), "{}", name);
+        assert_eq!(signal.short_name(), &name[3..]);
+        assert_eq!(Signal::parse_kill_arg(signal.short_name()), Some(signal));
+        assert_eq!(Signal::parse_kill_arg(name), Some(signal));
+    }
+}
+
+#[test]
+fn exit_code() {
+    assert_eq!(Signal::Int.exit_code(), 130);
+    assert_eq!(Signal::Term.exit_code(), 143);
+}
+
+#[test]
+fn iter_catchable() {
+    let signals: Vec<_> = Signal::iter_catchable().collect();
+
+    assert!(!signals.contains(&Signal::Kill));
+    assert!(!signals.contains(&Signal::Stop));
+    assert!(signals.contains(&Signal::Int));
+    assert!(signals.iter().all(|signal| signal.can_catch()));
+
+    let all = (0..64).filter_map(Signal::from_signal_hook).count();
+    assert_eq!(signals.len(), all - 2);
+}
+
+#[test]
+fn describe() {
+    assert_eq!(Signal::Term.describe(), "Termination request");
+    assert_eq!(Signal::Hup.describe(), "Hangup / reload");
+
+    for signal in (0..64).filter_map(Signal::from_signal_hook) {
+        assert!(!signal.describe().is_empty(), "{:?}", signal);
+    }
+}
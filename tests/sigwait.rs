@@ -0,0 +1,101 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use async_signal::{BlockingSignals, Signal};
+
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::raise;
+
+/// Block the given signals in the current thread.
+fn block(signals: &BlockingSignals) {
+    let sigset: libc::sigset_t = signals.signals().into();
+
+    unsafe {
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_BLOCK, &sigset, ptr::null_mut()),
+            0
+        );
+    }
+}
+
+#[test]
+fn wait() {
+    let signals = BlockingSignals::new([Signal::Usr1]);
+    block(&signals);
+
+    raise(Signal::Usr1, 1);
+
+    assert_eq!(signals.wait().unwrap(), Signal::Usr1);
+}
+
+#[test]
+fn wait_timeout() {
+    let signals = BlockingSignals::new([Signal::Usr2]);
+    block(&signals);
+
+    assert_eq!(
+        signals.wait_timeout(Duration::from_millis(10)).unwrap(),
+        None
+    );
+
+    raise(Signal::Usr2, 1);
+
+    assert_eq!(
+        signals.wait_timeout(Duration::from_secs(5)).unwrap(),
+        Some(Signal::Usr2)
+    );
+
+    // The signal was consumed, so it is no longer pending.
+    let mut pending = MaybeUninit::<libc::sigset_t>::uninit();
+    let pending = unsafe {
+        libc::sigpending(pending.as_mut_ptr());
+        pending.assume_init()
+    };
+    assert_eq!(unsafe { libc::sigismember(&pending, libc::SIGUSR2) }, 0);
+}
+
+extern "C" fn interrupt(_: i32) {}
+
+#[test]
+fn wait_timeout_interrupted() {
+    let signals = BlockingSignals::new([Signal::Alarm]);
+    block(&signals);
+
+    // Interrupt the wait over and over with a signal that has a handler.
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        action.sa_sigaction = interrupt as extern "C" fn(i32) as usize;
+        assert_eq!(libc::sigaction(libc::SIGWINCH, &action, ptr::null_mut()), 0);
+    }
+
+    // Give up interrupting after a while, so that a wait that restarts every time still ends.
+    let thread = unsafe { libc::pthread_self() };
+    let start = Instant::now();
+    let done = Arc::new(AtomicBool::new(false));
+    let interrupter = thread::spawn({
+        let done = done.clone();
+        move || {
+            while !done.load(Ordering::SeqCst) && start.elapsed() < Duration::from_secs(2) {
+                unsafe {
+                    libc::pthread_kill(thread, libc::SIGWINCH);
+                }
+                thread::sleep(Duration::from_millis(20));
+            }
+        }
+    });
+
+    let result = signals.wait_timeout(Duration::from_millis(200)).unwrap();
+    let elapsed = start.elapsed();
+    done.store(true, Ordering::SeqCst);
+    interrupter.join().unwrap();
+
+    assert_eq!(result, None);
+    assert!(elapsed < Duration::from_secs(1), "{:?}", elapsed);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use std::io;
+use std::thread;
+
+#[test]
+fn owned_stream() {
+    fn assert_static_stream<S: Stream<Item = io::Result<Signal>> + Send + Sync + 'static>(_: &S) {}
+
+    let signals = Signals::new(Some(Signal::Int)).unwrap();
+    assert_static_stream(&signals);
+    signals.inject(Signal::Int);
+
+    // Move the stream into another thread and drive it there.
+    let received = thread::spawn(move || {
+        future::block_on(signals.take(1).collect::<Vec<_>>())
+            .into_iter()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(received.join().unwrap(), [Signal::Int]);
+}
+
+#[cfg(unix)]
+#[test]
+fn owned_and_shared_streams_agree() {
+    use std::time::Duration;
+
+    let mut signals = Signals::new([Signal::Usr1, Signal::Usr2]).unwrap();
+
+    let sequence = [Signal::Usr1, Signal::Usr2, Signal::Usr2, Signal::Usr1];
+
+    future::block_on(async {
+        // Alternate between the two implementations on the same instance.
+        for signal in sequence {
+            common::raise(signal, 1);
+        }
+        let mut received = Vec::new();
+        for i in 0..sequence.len() {
+            let signal = if i % 2 == 0 {
+                signals.next().await
+            } else {
+                (&signals).next().await
+            };
+            received.push(signal.unwrap().unwrap());
+        }
+        assert_eq!(received, sequence);
+
+        // Both are pending once everything is taken.
+        assert!(future::poll_once(signals.next()).await.is_none());
+        assert!(future::poll_once((&signals).next()).await.is_none());
+    });
+
+    // Both are woken up by a new signal.
+    for owned in [true, false] {
+        thread::scope(|s| {
+            s.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                common::raise(Signal::Usr2, 1);
+            });
+
+            let signal = if owned {
+                future::block_on(signals.next())
+            } else {
+                future::block_on((&signals).next())
+            };
+            assert_eq!(signal.unwrap().unwrap(), Signal::Usr2);
+        });
+    }
+}
@@ -0,0 +1,41 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use std::thread;
+
+use common::raise;
+
+const COUNT: usize = 10_000;
+
+#[test]
+fn concurrent_raise_and_drain() {
+    let mut signals = Signals::new([Signal::Usr1, Signal::Usr2]).unwrap();
+    signals.set_unbounded(true);
+
+    thread::scope(|s| {
+        // Raise signals from two threads, so that the handlers run concurrently with the drain.
+        for signal in [Signal::Usr1, Signal::Usr2] {
+            s.spawn(move || raise(signal, COUNT));
+        }
+
+        // Every signal must be received, without missing a wakeup.
+        let (mut usr1, mut usr2) = (0, 0);
+        future::block_on(async {
+            while usr1 + usr2 < 2 * COUNT {
+                match (&signals).next().await.unwrap().unwrap() {
+                    Signal::Usr1 => usr1 += 1,
+                    Signal::Usr2 => usr2 += 1,
+                    signal => panic!("unexpected signal {:?}", signal),
+                }
+            }
+        });
+
+        assert_eq!((usr1, usr2), (COUNT, COUNT));
+    });
+
+    assert!(signals.try_next_signal().unwrap().is_none());
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "testing")]
+
+use async_signal::testing::MockSignals;
+use async_signal::Signal;
+use futures_lite::{future, prelude::*};
+
+use std::io;
+
+/// Signal handling logic that is generic over the stream, as it would be in an application.
+async fn count_until_term<S>(mut signals: S) -> io::Result<usize>
+where
+    S: Stream<Item = io::Result<Signal>> + Unpin,
+{
+    let mut hups = 0;
+
+    while let Some(signal) = signals.next().await {
+        match signal? {
+            Signal::Hup => hups += 1,
+            Signal::Term => break,
+            _ => {}
+        }
+    }
+
+    Ok(hups)
+}
+
+#[test]
+fn deterministic_delivery() {
+    let signals = MockSignals::new([Signal::Hup, Signal::Term]);
+    signals.inject(Signal::Hup);
+    signals.inject(Signal::Int);
+    signals.inject(Signal::Hup);
+    signals.inject(Signal::Term);
+
+    // SIGINT isn't registered, so it is discarded.
+    assert_eq!(future::block_on(count_until_term(&signals)).unwrap(), 2);
+    assert!(signals.try_next_signal().unwrap().is_none());
+}
+
+#[test]
+fn wakes_up() {
+    let mut signals = MockSignals::new([Signal::Usr1]);
+
+    future::block_on(async {
+        assert!(future::poll_once(signals.next()).await.is_none());
+        signals.inject(Signal::Usr1);
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Usr1);
+    });
+
+    // Removed signals are discarded, even if they were already delivered.
+    signals.inject(Signal::Usr1);
+    signals.remove_signals([Signal::Usr1]);
+    assert!(signals.try_next_signal().unwrap().is_none());
+    assert!(signals.is_empty());
+}
@@ -0,0 +1,22 @@
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use std::time::Instant;
+
+#[test]
+fn timestamped() {
+    let signals = Signals::new(Some(Signal::Int)).unwrap();
+    let start = Instant::now();
+    signals.inject(Signal::Int);
+    signals.inject(Signal::Int);
+
+    future::block_on(async {
+        let mut stream = signals.timestamped();
+        let (first, t1) = stream.next().await.unwrap().unwrap();
+        let (second, t2) = stream.next().await.unwrap().unwrap();
+
+        assert_eq!((first, second), (Signal::Int, Signal::Int));
+        assert!(start <= t1);
+        assert!(t1 <= t2);
+    });
+}
@@ -0,0 +1,30 @@
+#![cfg(unix)]
+
+mod common;
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use common::raise;
+
+const COUNT: usize = 10_000;
+
+#[test]
+fn unbounded() {
+    let mut signals = Signals::new(Some(Signal::Usr1)).unwrap();
+    signals.set_unbounded(true);
+
+    // Raise far more signals than the pipe can hold.
+    for _ in 0..COUNT {
+        raise(Signal::Usr1, 1);
+    }
+
+    future::block_on(async {
+        for _ in 0..COUNT {
+            assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Usr1);
+        }
+
+        assert!(future::poll_once(signals.next()).await.is_none());
+    });
+    assert!(signals.try_next_signal().unwrap().is_none());
+}
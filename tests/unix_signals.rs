@@ -1,8 +1,12 @@
 #![cfg(unix)]
 
+mod common;
+
 use async_signal::{Signal, Signals};
 use futures_lite::{future, prelude::*};
 
+use common::raise;
+
 #[test]
 fn endurance() {
     let user_signals = [Signal::Usr1, Signal::Usr2];
@@ -13,9 +17,7 @@ fn endurance() {
         for &signal in user_signals.iter().cycle().take(100_000) {
             let mut next = signals.next();
             assert!(future::poll_once(&mut next).await.is_none());
-            unsafe {
-                libc::raise(signal as libc::c_int);
-            }
+            raise(signal, 1);
             assert_eq!(
                 future::poll_once(&mut next)
                     .await
@@ -37,9 +39,7 @@ fn distanced() {
             let mut rng = fastrand::Rng::new();
 
             for _ in 0..1_000 {
-                unsafe {
-                    libc::raise(Signal::Alarm as libc::c_int);
-                }
+                raise(Signal::Alarm, 1);
 
                 if rng.bool() {
                     async_io::Timer::after(std::time::Duration::from_millis(rng.u64(1..5))).await;
@@ -56,3 +56,268 @@ fn distanced() {
         assert!(future::poll_once(signals.next()).await.is_none());
     });
 }
+
+#[test]
+fn empty() {
+    future::block_on(async {
+        let mut signals = Signals::new_empty().unwrap();
+
+        // Nothing is registered, so the stream should stay pending.
+        assert!(future::poll_once(signals.next()).await.is_none());
+
+        // Add a signal and make sure it is received.
+        signals.add_signals(Some(Signal::Hup)).unwrap();
+        assert!(future::poll_once(signals.next()).await.is_none());
+        raise(Signal::Hup, 1);
+        assert_eq!(signals.next().await.unwrap().unwrap(), Signal::Hup);
+    });
+}
+
+#[test]
+fn batch() {
+    let signals = Signals::new([Signal::Winch, Signal::Urg]).unwrap();
+
+    for _ in 0..2 {
+        raise(Signal::Winch, 1);
+        raise(Signal::Urg, 1);
+    }
+
+    future::block_on(async {
+        let mut buf = Vec::new();
+
+        let count = future::poll_fn(|cx| signals.poll_next_batch(cx, &mut buf, 3))
+            .await
+            .unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(buf, [Signal::Winch, Signal::Urg, Signal::Winch]);
+
+        let count = future::poll_fn(|cx| signals.poll_next_batch(cx, &mut buf, 10))
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(buf[3..], [Signal::Urg]);
+
+        let mut batch = future::poll_fn(|cx| signals.poll_next_batch(cx, &mut buf, 10));
+        assert!(future::poll_once(&mut batch).await.is_none());
+    });
+}
+
+#[test]
+fn select() {
+    use futures::stream::{FusedStream, StreamExt as _};
+
+    future::block_on(async {
+        let mut signals = Signals::new(Some(Signal::Cont)).unwrap();
+        assert!(!signals.is_terminated());
+
+        let mut ticks = futures::stream::repeat(());
+        let mut ticked = 0;
+
+        loop {
+            futures::select! {
+                signal = signals.select_next_some() => {
+                    assert_eq!(signal.unwrap(), Signal::Cont);
+                    break;
+                }
+                _ = ticks.select_next_some() => {
+                    // Raise the signal once the other stream has produced all of its items.
+                    ticked += 1;
+                    if ticked == 3 {
+                        raise(Signal::Cont, 1);
+                    }
+                }
+            }
+        }
+
+        assert!(ticked >= 3);
+        assert!(!signals.is_terminated());
+    });
+}
+
+#[test]
+fn len() {
+    let mut signals = Signals::new_empty().unwrap();
+    assert!(signals.is_empty());
+    assert_eq!(signals.len(), 0);
+
+    signals.add_signals([Signal::Term, Signal::Quit]).unwrap();
+    assert!(!signals.is_empty());
+    assert_eq!(signals.len(), 2);
+
+    // Adding a signal twice doesn't count it twice.
+    signals.add_signals([Signal::Term, Signal::Pipe]).unwrap();
+    assert_eq!(signals.len(), 3);
+
+    signals
+        .remove_signals([Signal::Term, Signal::Xcpu])
+        .unwrap();
+    assert_eq!(signals.len(), 2);
+
+    signals
+        .remove_signals([Signal::Quit, Signal::Pipe])
+        .unwrap();
+    assert!(signals.is_empty());
+}
+
+#[test]
+fn wait_for_any() {
+    let signals = Signals::new([Signal::Prof, Signal::Vtalarm]).unwrap();
+
+    raise(Signal::Prof, 1);
+    raise(Signal::Vtalarm, 1);
+
+    future::block_on(async {
+        assert_eq!(
+            signals.wait_for_any(&[Signal::Vtalarm]).await.unwrap(),
+            Signal::Vtalarm
+        );
+
+        // The non-matching signal was discarded.
+        assert!(signals.try_next_signal().unwrap().is_none());
+    });
+}
+
+#[test]
+fn counts() {
+    let mut signals = Signals::new([Signal::Xcpu, Signal::Xfsz]).unwrap();
+    assert_eq!(signals.counts().count(), 0);
+    signals.set_counting(true);
+
+    raise(Signal::Xcpu, 1);
+    raise(Signal::Xfsz, 1);
+    raise(Signal::Xcpu, 1);
+
+    future::block_on(async {
+        for _ in 0..3 {
+            signals.next().await.unwrap().unwrap();
+        }
+    });
+
+    assert_eq!(
+        signals.counts().collect::<Vec<_>>(),
+        [(Signal::Xcpu, 2), (Signal::Xfsz, 1)]
+    );
+
+    signals.set_counting(false);
+    assert_eq!(signals.counts().count(), 0);
+}
+
+#[test]
+fn sig_id() {
+    let signals = Signals::new(Some(Signal::Sys)).unwrap();
+    assert!(signals.sig_id(Signal::Sys).is_some());
+    assert!(signals.sig_id(Signal::Ttin).is_none());
+}
+
+#[test]
+fn recv_all() {
+    let signals = Signals::new([Signal::Child, Signal::Io, Signal::Tstp]).unwrap();
+
+    future::block_on(async {
+        let mut recv = Box::pin(signals.recv_all());
+        assert!(future::poll_once(&mut recv).await.is_none());
+        drop(recv);
+
+        raise(Signal::Child, 1);
+        raise(Signal::Io, 1);
+        raise(Signal::Tstp, 1);
+
+        assert_eq!(
+            signals.recv_all().await.unwrap(),
+            [Signal::Child, Signal::Io, Signal::Tstp]
+        );
+        assert!(future::poll_once(signals.recv_all()).await.is_none());
+    });
+}
+
+#[test]
+fn initial_tick() {
+    let signals = Signals::new([Signal::Ttin])
+        .unwrap()
+        .with_initial_tick(Signal::Ttin);
+
+    future::block_on(async {
+        assert_eq!(signals.recv_all().await.unwrap(), [Signal::Ttin]);
+        assert!(signals.try_next_signal().unwrap().is_none());
+    });
+}
+
+#[test]
+fn arm() {
+    let mut signals = Signals::new([Signal::Ttou]).unwrap();
+    signals.disarm(Signal::Ttou);
+    raise(Signal::Ttou, 1);
+    assert!(signals.try_next_signal().unwrap().is_none());
+
+    signals.arm(Signal::Ttou);
+    raise(Signal::Ttou, 1);
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Ttou));
+    assert!(signals.try_next_signal().unwrap().is_none());
+}
+
+#[test]
+fn reload_on() {
+    let signals = Signals::new([Signal::Trap]).unwrap();
+
+    raise(Signal::Trap, 2);
+
+    // Stop after the second reload.
+    let mut reloads = 0;
+    let result = future::block_on(signals.reload_on(Signal::Trap, || {
+        reloads += 1;
+        match reloads {
+            1 => Ok(()),
+            _ => Err(std::io::Error::from(std::io::ErrorKind::Other)),
+        }
+    }));
+
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::Other);
+    assert_eq!(reloads, 2);
+}
+
+#[test]
+fn add_signals_each() {
+    let mut signals = Signals::new_empty().unwrap();
+    let results = signals.add_signals_each([Signal::Pipe, Signal::Kill, Signal::Sys, Signal::Segv]);
+
+    let results: Vec<_> = results
+        .into_iter()
+        .map(|(signal, result)| (signal, result.map_err(|e| e.kind())))
+        .collect();
+    assert_eq!(
+        results,
+        [
+            (Signal::Pipe, Ok(())),
+            (Signal::Kill, Err(std::io::ErrorKind::InvalidInput)),
+            (Signal::Sys, Ok(())),
+            (Signal::Segv, Err(std::io::ErrorKind::InvalidInput)),
+        ]
+    );
+    assert_eq!(signals.len(), 2);
+
+    // The whole batch fails when adding them all at once.
+    assert!(signals.add_signals([Signal::Stop]).is_err());
+}
+
+#[test]
+fn add_signals_rolls_back() {
+    let mut signals = Signals::new([Signal::Usr1]).unwrap();
+
+    // SIGKILL can't be registered, so none of the new signals are kept.
+    let err = signals
+        .add_signals([
+            Signal::Usr1,
+            Signal::Hup,
+            Signal::Quit,
+            Signal::Kill,
+            Signal::Term,
+        ])
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+    assert_eq!(signals.len(), 1);
+    assert!(signals.sig_id(Signal::Usr1).is_some());
+    for signal in [Signal::Hup, Signal::Quit, Signal::Term] {
+        assert!(signals.sig_id(signal).is_none(), "{:?}", signal);
+    }
+}
@@ -0,0 +1,20 @@
+use async_signal::{Signal, Signals};
+use futures_lite::future;
+
+#[test]
+fn wait_for_n() {
+    let signals = Signals::new(Some(Signal::Int)).unwrap();
+
+    future::block_on(async {
+        let mut wait = Box::pin(signals.wait_for_n(Signal::Int, 2));
+        assert!(future::poll_once(&mut wait).await.is_none());
+
+        signals.inject(Signal::Int);
+        assert!(future::poll_once(&mut wait).await.is_none());
+
+        signals.inject(Signal::Int);
+        wait.await.unwrap();
+
+        signals.wait_for_n(Signal::Int, 0).await.unwrap();
+    });
+}
@@ -0,0 +1,69 @@
+#![cfg(windows)]
+
+use async_signal::{Signal, Signals};
+
+use std::os::windows::io::AsRawHandle;
+
+use windows_sys::Win32::Foundation::{WAIT_OBJECT_0, WAIT_TIMEOUT};
+use windows_sys::Win32::System::Console::{GenerateConsoleCtrlEvent, CTRL_C_EVENT};
+use windows_sys::Win32::System::Threading::WaitForSingleObject;
+
+#[test]
+fn readiness_handle() {
+    let signals = Signals::new(Some(Signal::Int)).unwrap();
+    let handle = signals.as_raw_handle() as _;
+
+    unsafe {
+        assert_eq!(WaitForSingleObject(handle, 0), WAIT_TIMEOUT);
+        GenerateConsoleCtrlEvent(CTRL_C_EVENT, 0);
+        assert_eq!(WaitForSingleObject(handle, 5_000), WAIT_OBJECT_0);
+    }
+
+    assert_eq!(signals.try_next_signal().unwrap(), Some(Signal::Int));
+
+    unsafe {
+        assert_eq!(WaitForSingleObject(handle, 0), WAIT_TIMEOUT);
+    }
+}
+
+/// Set in the child process spawned by `handler_uninstalled_after_drop`.
+const CHILD_VAR: &str = "ASYNC_SIGNAL_UNINSTALL_CHILD";
+
+#[test]
+fn handler_uninstalled_after_drop() {
+    use std::os::windows::process::CommandExt;
+    use std::process::Command;
+    use windows_sys::Win32::System::Console::CTRL_BREAK_EVENT;
+    use windows_sys::Win32::System::Threading::{GetCurrentProcessId, CREATE_NEW_PROCESS_GROUP};
+
+    /// The exit code of a process that was terminated by a console control event.
+    const STATUS_CONTROL_C_EXIT: u32 = 0xC000013A;
+
+    if std::env::var_os(CHILD_VAR).is_some() {
+        drop(Signals::new([Signal::Int, Signal::Break]).unwrap());
+
+        // With no handlers left, the default handler should terminate the process. This process
+        // leads its own process group, so only it receives the event.
+        unsafe {
+            GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, GetCurrentProcessId());
+        }
+        std::thread::sleep(std::time::Duration::from_secs(5));
+        std::process::exit(0);
+    }
+
+    // Run this test again in a child process, since it is supposed to die.
+    let status = Command::new(std::env::current_exe().unwrap())
+        .args([
+            "--exact",
+            "handler_uninstalled_after_drop",
+            "--test-threads=1",
+        ])
+        .env(CHILD_VAR, "1")
+        .creation_flags(CREATE_NEW_PROCESS_GROUP)
+        .status()
+        .unwrap();
+    assert_eq!(
+        status.code().map(|code| code as u32),
+        Some(STATUS_CONTROL_C_EXIT)
+    );
+}
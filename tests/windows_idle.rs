@@ -0,0 +1,59 @@
+//! This is in its own file so that other tests don't use CPU time while it is measured.
+
+#![cfg(windows)]
+
+use async_signal::{Signal, Signals};
+use futures_lite::{future, prelude::*};
+
+use std::thread;
+use std::time::Duration;
+
+use windows_sys::Win32::Foundation::FILETIME;
+use windows_sys::Win32::System::Threading::{GetCurrentProcess, GetProcessTimes};
+
+/// Get the CPU time used by this process so far.
+fn cpu_time() -> Duration {
+    let zero = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    let (mut creation, mut exit, mut kernel, mut user) = (zero, zero, zero, zero);
+
+    unsafe {
+        assert_ne!(
+            GetProcessTimes(
+                GetCurrentProcess(),
+                &mut creation,
+                &mut exit,
+                &mut kernel,
+                &mut user
+            ),
+            0
+        );
+    }
+
+    // FILETIMEs count 100 nanosecond intervals.
+    let ticks = |time: FILETIME| (time.dwHighDateTime as u64) << 32 | time.dwLowDateTime as u64;
+    Duration::from_nanos((ticks(kernel) + ticks(user)) * 100)
+}
+
+#[test]
+fn idle_uses_no_cpu() {
+    let signals = Signals::new([Signal::Int]).unwrap();
+
+    thread::scope(|s| {
+        // Wait for a signal in the background.
+        let waiter = s.spawn(|| future::block_on((&signals).next()));
+        thread::sleep(Duration::from_millis(100));
+
+        let before = cpu_time();
+        thread::sleep(Duration::from_secs(1));
+        let used = cpu_time() - before;
+
+        // A waiter that polls in a loop would use the whole second.
+        assert!(used < Duration::from_millis(100), "{:?}", used);
+
+        signals.inject(Signal::Int);
+        assert_eq!(waiter.join().unwrap().unwrap().unwrap(), Signal::Int);
+    });
+}